@@ -841,7 +841,7 @@ impl<'a> Parser<'a> {
 pub struct Compiler<'a> {
     pub context: &'a Context,
     pub builder: &'a Builder,
-    pub fpm: &'a PassManager,
+    pub fpm: &'a PassManager<FunctionValue>,
     pub module: &'a Module,
     pub function: &'a Function,
 
@@ -1103,7 +1103,7 @@ impl<'a> Compiler<'a> {
         let fn_val = self.module.add_function(proto.name.as_str(), &fn_type, None);
 
         // set arguments names
-        for (i, arg) in fn_val.params().enumerate() {
+        for (i, arg) in fn_val.get_param_iter().enumerate() {
             arg.into_float_value().set_name(proto.args[i].as_str());
         }
 
@@ -1125,7 +1125,7 @@ impl<'a> Compiler<'a> {
         // build variables map
         self.variables.reserve(proto.args.len());
 
-        for (i, arg) in function.params().enumerate() {
+        for (i, arg) in function.get_param_iter().enumerate() {
             let arg_name = proto.args[i].as_str();
             let alloca = self.create_entry_block_alloca(arg_name, Some(&entry));
 
@@ -1141,7 +1141,7 @@ impl<'a> Compiler<'a> {
 
         // return the whole thing after verification and optimization
         if function.verify(true) {
-            self.fpm.run_on_function(&function);
+            self.fpm.run_on(&function);
 
             Ok(function)
         } else {
@@ -1154,7 +1154,7 @@ impl<'a> Compiler<'a> {
     }
 
     /// Compiles the specified `Function` in the given `Context` and using the specified `Builder`, `PassManager`, and `Module`.
-    pub fn compile(context: &'a Context, builder: &'a Builder, pass_manager: &'a PassManager, module: &'a Module, function: &Function) -> Result<FunctionValue, &'static str> {
+    pub fn compile(context: &'a Context, builder: &'a Builder, pass_manager: &'a PassManager<FunctionValue>, module: &'a Module, function: &Function) -> Result<FunctionValue, &'static str> {
         let mut compiler = Compiler {
             context: context,
             builder: builder,
@@ -1225,7 +1225,7 @@ pub fn main() {
     let builder = context.create_builder();
 
     // Create FPM
-    let fpm = PassManager::create_for_function(&module);
+    let fpm = PassManager::create_function_pass_manager(&module);
 
     fpm.add_instruction_combining_pass();
     fpm.add_reassociate_pass();
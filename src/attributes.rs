@@ -0,0 +1,234 @@
+//! `Attribute`s are optional modifiers to functions, function parameters, and return types.
+
+use llvm_sys::prelude::LLVMAttributeRef;
+use llvm_sys::core::{LLVMGetEnumAttributeKindForName, LLVMGetLastEnumAttributeKind, LLVMGetEnumAttributeKind, LLVMGetEnumAttributeValue, LLVMGetStringAttributeKind, LLVMGetStringAttributeValue, LLVMIsEnumAttribute, LLVMIsStringAttribute};
+
+use std::slice;
+
+/// Functions, function parameters, and return types can have `Attribute`s to indicate
+/// how they should be treated by optimizations and code generation.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Attribute {
+    pub(crate) attribute: LLVMAttributeRef,
+}
+
+impl Attribute {
+    pub(crate) fn new(attribute: LLVMAttributeRef) -> Self {
+        assert!(!attribute.is_null());
+
+        Attribute {
+            attribute,
+        }
+    }
+
+    /// Determines whether or not an `Attribute` is an enum. This method will
+    /// likely be removed in the future in favor of `Attribute`s being generically
+    /// defined.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let enum_attribute = context.create_enum_attribute(0, 10);
+    ///
+    /// assert!(enum_attribute.is_enum());
+    /// ```
+    pub fn is_enum(&self) -> bool {
+        unsafe {
+            LLVMIsEnumAttribute(self.attribute) == 1
+        }
+    }
+
+    /// Determines whether or not an `Attribute` is a string. This method will
+    /// likely be removed in the future in favor of `Attribute`s being generically
+    /// defined.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let string_attribute = context.create_string_attribute("my_key_123", "my_val");
+    ///
+    /// assert!(string_attribute.is_string());
+    /// ```
+    pub fn is_string(&self) -> bool {
+        unsafe {
+            LLVMIsStringAttribute(self.attribute) == 1
+        }
+    }
+
+    /// Gets the enum kind id associated with a builtin name. Since these ids vary
+    /// across LLVM versions, this should be preferred over hardcoding them.
+    /// Returns `0` if the name is not found.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::attributes::Attribute;
+    ///
+    /// // This kind id doesn't exist:
+    /// assert_eq!(Attribute::get_named_enum_kind_id("foobar"), 0);
+    ///
+    /// // These are real kind ids:
+    /// assert_ne!(Attribute::get_named_enum_kind_id("nounwind"), 0);
+    /// assert_ne!(Attribute::get_named_enum_kind_id("noinline"), 0);
+    /// ```
+    pub fn get_named_enum_kind_id(name: &str) -> u32 {
+        unsafe {
+            LLVMGetEnumAttributeKindForName(name.as_ptr() as *const _, name.len())
+        }
+    }
+
+    /// Gets the last enum kind id that LLVM knows of. All valid enum kind ids
+    /// lie between `1` and this value, inclusive.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::attributes::Attribute;
+    ///
+    /// assert!(Attribute::get_named_enum_kind_id("nounwind") <= Attribute::get_last_enum_kind_id());
+    /// ```
+    pub fn get_last_enum_kind_id() -> u32 {
+        unsafe {
+            LLVMGetLastEnumAttributeKind()
+        }
+    }
+
+    /// Gets the kind id associated with an enum `Attribute`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this `Attribute` is not an enum.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let enum_attribute = context.create_enum_attribute(0, 10);
+    ///
+    /// assert_eq!(enum_attribute.get_enum_kind_id(), 0);
+    /// ```
+    pub fn get_enum_kind_id(&self) -> u32 {
+        assert!(self.is_enum()); // FIXME: SubTypes
+
+        unsafe {
+            LLVMGetEnumAttributeKind(self.attribute)
+        }
+    }
+
+    /// Gets the value associated with an enum `Attribute`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this `Attribute` is not an enum.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let enum_attribute = context.create_enum_attribute(0, 10);
+    ///
+    /// assert_eq!(enum_attribute.get_enum_value(), 10);
+    /// ```
+    pub fn get_enum_value(&self) -> u64 {
+        assert!(self.is_enum()); // FIXME: SubTypes
+
+        unsafe {
+            LLVMGetEnumAttributeValue(self.attribute)
+        }
+    }
+
+    /// Gets the string kind id associated with a string `Attribute`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this `Attribute` is not a string.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let string_attribute = context.create_string_attribute("my_key", "my_val");
+    ///
+    /// assert_eq!(string_attribute.get_string_kind_id(), b"my_key");
+    /// ```
+    pub fn get_string_kind_id(&self) -> &[u8] {
+        assert!(self.is_string()); // FIXME: SubTypes
+
+        // LLVM returns the data of a StringRef, which isn't guaranteed to be nul terminated
+        let mut length = 0;
+        let ptr = unsafe {
+            LLVMGetStringAttributeKind(self.attribute, &mut length)
+        };
+
+        unsafe {
+            slice::from_raw_parts(ptr as *const u8, length as usize)
+        }
+    }
+
+    /// Gets the string value associated with a string `Attribute`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this `Attribute` is not a string.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let string_attribute = context.create_string_attribute("my_key", "my_val");
+    ///
+    /// assert_eq!(string_attribute.get_string_value(), b"my_val");
+    /// ```
+    pub fn get_string_value(&self) -> &[u8] {
+        assert!(self.is_string()); // FIXME: SubTypes
+
+        // LLVM returns the data of a StringRef, which isn't guaranteed to be nul terminated
+        let mut length = 0;
+        let ptr = unsafe {
+            LLVMGetStringAttributeValue(self.attribute, &mut length)
+        };
+
+        unsafe {
+            slice::from_raw_parts(ptr as *const u8, length as usize)
+        }
+    }
+}
+
+/// An `AttributeLoc` determines where on a function an attribute is assigned to.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AttributeLoc {
+    /// Assign to the `FunctionValue`'s return type.
+    Return,
+    /// Assign to one of the `FunctionValue`'s params (0-indexed).
+    Param(u32),
+    /// Assign to the `FunctionValue` itself.
+    Function,
+}
+
+impl AttributeLoc {
+    pub(crate) fn get_index(&self) -> u32 {
+        match *self {
+            AttributeLoc::Return => 0,
+            AttributeLoc::Param(index) => {
+                assert!(index <= u32::MAX - 2, "Param index must be <= u32::MAX - 2");
+
+                index + 1
+            },
+            AttributeLoc::Function => u32::MAX,
+        }
+    }
+}
@@ -136,6 +136,8 @@ impl BasicBlock {
 
     /// Prepends one `BasicBlock` before another.
     ///
+    /// An error is returned and both blocks are left untouched if either has no parent.
+    ///
     /// # Example
     /// ```no_run
     /// use inkwell::context::Context;
@@ -151,20 +153,29 @@ impl BasicBlock {
     /// let basic_block1 = context.append_basic_block(&function, "entry");
     /// let basic_block2 = context.append_basic_block(&function, "next");
     ///
-    /// basic_block2.move_before(&basic_block1);
+    /// basic_block2.move_before(&basic_block1).unwrap();
     ///
     /// assert!(basic_block1.get_next_basic_block().is_none());
     /// assert_eq!(basic_block2.get_next_basic_block().unwrap(), basic_block1);
     /// ```
     // REVIEW: What happens if blocks are from different scopes?
-    pub fn move_before(&self, basic_block: &BasicBlock) {
+    pub fn move_before(&self, basic_block: &BasicBlock) -> Result<(), &'static str> {
+        // This method is UB if either block has no parent, so we must check for them (or encode into type system)
+        if self.get_parent().is_none() || basic_block.get_parent().is_none() {
+            return Err("Both BasicBlocks must belong to a function.");
+        }
+
         unsafe {
             LLVMMoveBasicBlockBefore(self.basic_block, basic_block.basic_block)
         }
+
+        Ok(())
     }
 
     /// Appends one `BasicBlock` after another.
     ///
+    /// An error is returned and both blocks are left untouched if either has no parent.
+    ///
     /// # Example
     /// ```no_run
     /// use inkwell::context::Context;
@@ -180,16 +191,23 @@ impl BasicBlock {
     /// let basic_block1 = context.append_basic_block(&function, "entry");
     /// let basic_block2 = context.append_basic_block(&function, "next");
     ///
-    /// basic_block1.move_after(&basic_block2);
+    /// basic_block1.move_after(&basic_block2).unwrap();
     ///
     /// assert!(basic_block1.get_next_basic_block().is_none());
     /// assert_eq!(basic_block2.get_next_basic_block().unwrap(), basic_block1);
     /// ```
     // REVIEW: What happens if blocks are from different scopes?
-    pub fn move_after(&self, basic_block: &BasicBlock) {
+    pub fn move_after(&self, basic_block: &BasicBlock) -> Result<(), &'static str> {
+        // This method is UB if either block has no parent, so we must check for them (or encode into type system)
+        if self.get_parent().is_none() || basic_block.get_parent().is_none() {
+            return Err("Both BasicBlocks must belong to a function.");
+        }
+
         unsafe {
             LLVMMoveBasicBlockAfter(self.basic_block, basic_block.basic_block)
         }
+
+        Ok(())
     }
 
     /// Prepends a new `BasicBlock` before this one.
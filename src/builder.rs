@@ -1,12 +1,14 @@
 use either::Either;
-use llvm_sys::core::{LLVMBuildAdd, LLVMBuildAlloca, LLVMBuildAnd, LLVMBuildArrayAlloca, LLVMBuildArrayMalloc, LLVMBuildBr, LLVMBuildCall, LLVMBuildCast, LLVMBuildCondBr, LLVMBuildExtractValue, LLVMBuildFAdd, LLVMBuildFCmp, LLVMBuildFDiv, LLVMBuildFence, LLVMBuildFMul, LLVMBuildFNeg, LLVMBuildFree, LLVMBuildFSub, LLVMBuildGEP, LLVMBuildICmp, LLVMBuildInsertValue, LLVMBuildIsNotNull, LLVMBuildIsNull, LLVMBuildLoad, LLVMBuildMalloc, LLVMBuildMul, LLVMBuildNeg, LLVMBuildNot, LLVMBuildOr, LLVMBuildPhi, LLVMBuildPointerCast, LLVMBuildRet, LLVMBuildRetVoid, LLVMBuildStore, LLVMBuildSub, LLVMBuildUDiv, LLVMBuildUnreachable, LLVMBuildXor, LLVMDisposeBuilder, LLVMGetElementType, LLVMGetInsertBlock, LLVMGetReturnType, LLVMGetTypeKind, LLVMInsertIntoBuilder, LLVMPositionBuilderAtEnd, LLVMTypeOf, LLVMSetTailCall, LLVMBuildExtractElement, LLVMBuildInsertElement, LLVMBuildIntToPtr, LLVMBuildPtrToInt, LLVMInsertIntoBuilderWithName, LLVMClearInsertionPosition, LLVMCreateBuilder, LLVMPositionBuilder, LLVMPositionBuilderBefore, LLVMBuildAggregateRet, LLVMBuildStructGEP, LLVMBuildInBoundsGEP, LLVMBuildPtrDiff, LLVMBuildNSWAdd, LLVMBuildNUWAdd, LLVMBuildNSWSub, LLVMBuildNUWSub, LLVMBuildNSWMul, LLVMBuildNUWMul, LLVMBuildSDiv, LLVMBuildSRem, LLVMBuildURem, LLVMBuildFRem, LLVMBuildNSWNeg, LLVMBuildNUWNeg, LLVMBuildFPToUI, LLVMBuildFPToSI, LLVMBuildSIToFP, LLVMBuildUIToFP, LLVMBuildFPTrunc, LLVMBuildFPExt, LLVMBuildIntCast, LLVMBuildFPCast, LLVMBuildSExtOrBitCast, LLVMBuildZExtOrBitCast, LLVMBuildTruncOrBitCast, LLVMBuildSwitch, LLVMAddCase, LLVMBuildShl, LLVMBuildAShr, LLVMBuildLShr, LLVMBuildGlobalString, LLVMBuildGlobalStringPtr, LLVMBuildExactSDiv, LLVMBuildTrunc, LLVMBuildSExt, LLVMBuildZExt};
+use llvm_sys::core::{LLVMBuildAdd, LLVMBuildAlloca, LLVMBuildAnd, LLVMBuildArrayAlloca, LLVMBuildArrayMalloc, LLVMBuildBr, LLVMBuildCall, LLVMBuildCast, LLVMBuildCondBr, LLVMBuildExtractValue, LLVMBuildFAdd, LLVMBuildFCmp, LLVMBuildFDiv, LLVMBuildFence, LLVMBuildFMul, LLVMBuildFNeg, LLVMBuildFree, LLVMBuildFSub, LLVMBuildGEP, LLVMBuildICmp, LLVMBuildInsertValue, LLVMBuildIsNotNull, LLVMBuildIsNull, LLVMBuildLoad, LLVMBuildMalloc, LLVMBuildMul, LLVMBuildNeg, LLVMBuildNot, LLVMBuildOr, LLVMBuildPhi, LLVMBuildPointerCast, LLVMBuildRet, LLVMBuildRetVoid, LLVMBuildStore, LLVMBuildSub, LLVMBuildUDiv, LLVMBuildUnreachable, LLVMBuildXor, LLVMDisposeBuilder, LLVMGetElementType, LLVMGetInsertBlock, LLVMGetReturnType, LLVMGetTypeKind, LLVMInsertIntoBuilder, LLVMPositionBuilderAtEnd, LLVMTypeOf, LLVMSetTailCall, LLVMBuildExtractElement, LLVMBuildInsertElement, LLVMBuildIntToPtr, LLVMBuildPtrToInt, LLVMInsertIntoBuilderWithName, LLVMClearInsertionPosition, LLVMCreateBuilder, LLVMPositionBuilder, LLVMPositionBuilderBefore, LLVMBuildAggregateRet, LLVMBuildStructGEP, LLVMBuildInBoundsGEP, LLVMBuildPtrDiff, LLVMBuildNSWAdd, LLVMBuildNUWAdd, LLVMBuildNSWSub, LLVMBuildNUWSub, LLVMBuildNSWMul, LLVMBuildNUWMul, LLVMBuildSDiv, LLVMBuildSRem, LLVMBuildURem, LLVMBuildFRem, LLVMBuildNSWNeg, LLVMBuildNUWNeg, LLVMBuildFPToUI, LLVMBuildFPToSI, LLVMBuildSIToFP, LLVMBuildUIToFP, LLVMBuildFPTrunc, LLVMBuildFPExt, LLVMBuildIntCast, LLVMBuildFPCast, LLVMBuildSExtOrBitCast, LLVMBuildZExtOrBitCast, LLVMBuildTruncOrBitCast, LLVMBuildSwitch, LLVMAddCase, LLVMBuildShl, LLVMBuildAShr, LLVMBuildLShr, LLVMBuildGlobalString, LLVMBuildGlobalStringPtr, LLVMBuildExactSDiv, LLVMBuildTrunc, LLVMBuildSExt, LLVMBuildZExt, LLVMBuildAtomicRMW, LLVMBuildAtomicCmpXchg, LLVMBuildSelect, LLVMBuildShuffleVector, LLVMBuildInvoke, LLVMBuildLandingPad, LLVMBuildResume, LLVMBuildBitCast, LLVMBuildAddrSpaceCast};
+#[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+use llvm_sys::core::LLVMSetOrdering;
 use llvm_sys::prelude::{LLVMBuilderRef, LLVMValueRef};
-use llvm_sys::{LLVMTypeKind, LLVMAtomicOrdering};
+use llvm_sys::LLVMTypeKind;
 
-use {IntPredicate, FloatPredicate};
+use {AtomicOrdering, AtomicRMWBinOp, IntPredicate, FloatPredicate};
 use basic_block::BasicBlock;
-use values::{AggregateValue, AsValueRef, BasicValue, BasicValueEnum, PhiValue, FunctionValue, IntValue, PointerValue, VectorValue, InstructionValue, GlobalValue, IntMathValue, FloatMathValue, PointerMathValue, InstructionOpcode};
-use types::{AsTypeRef, BasicType, IntMathType, FloatMathType, PointerMathType};
+use values::{AggregateValue, AggregateValueEnum, AsValueRef, BasicValue, BasicValueEnum, PhiValue, FunctionValue, IntValue, PointerValue, StructValue, VectorValue, InstructionValue, GlobalValue, IntMathValue, FloatMathValue, PointerMathValue, InstructionOpcode};
+use types::{AnyType, AnyTypeEnum, AsTypeRef, BasicType, IntMathType, FloatMathType, PointerMathType};
 
 use std::ffi::CString;
 
@@ -62,10 +64,53 @@ impl Builder {
         InstructionValue::new(value)
     }
 
-    pub fn build_call(&self, function: FunctionValue, args: &[BasicValueEnum], name: &str, tail_call: bool) -> Either<BasicValueEnum, InstructionValue> {
+    /// Builds a `call` instruction. Besides a `FunctionValue`, `function` may be a `PointerValue`
+    /// to a function, such as a function pointer loaded at runtime or an inline asm value from
+    /// `Context::create_inline_asm`. Returns the call's value, or its instruction if it returns void.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `function` is a `PointerValue` which doesn't point to a function.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    /// let callee = module.add_function("callee", &fn_type, None);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    /// let entry = fn_value.append_basic_block("entry");
+    /// let param = fn_value.get_first_param().unwrap();
+    ///
+    /// builder.position_at_end(&entry);
+    ///
+    /// let ret_val = builder.build_call(callee, &[param], "call", false).left().unwrap();
+    ///
+    /// builder.build_return(Some(&ret_val));
+    /// ```
+    pub fn build_call<F: Into<Either<FunctionValue, PointerValue>>>(&self, function: F, args: &[BasicValueEnum], name: &str, tail_call: bool) -> Either<BasicValueEnum, InstructionValue> {
+        let function = match function.into() {
+            Either::Left(function) => function.as_value_ref(),
+            Either::Right(function) => function.as_value_ref(),
+        };
+        let fn_type = unsafe {
+            LLVMGetElementType(LLVMTypeOf(function))
+        };
+        let fn_type_kind = unsafe {
+            LLVMGetTypeKind(fn_type)
+        };
+
+        // Calling a pointer to anything else would trip an assertion in LLVM, or worse
+        assert_eq!(fn_type_kind, LLVMTypeKind::LLVMFunctionTypeKind, "build_call requires a pointer to a function");
+
         // LLVM gets upset when void calls are named because they don't return anything
         let name = unsafe {
-            match LLVMGetTypeKind(LLVMGetReturnType(LLVMGetElementType(LLVMTypeOf(function.as_value_ref())))) {
+            match LLVMGetTypeKind(LLVMGetReturnType(fn_type)) {
                 LLVMTypeKind::LLVMVoidTypeKind => "",
                 _ => name,
             }
@@ -76,7 +121,7 @@ impl Builder {
                                               .map(|val| val.as_value_ref())
                                               .collect();
         let value = unsafe {
-            LLVMBuildCall(self.builder, function.as_value_ref(), args.as_mut_ptr(), args.len() as u32, c_string.as_ptr())
+            LLVMBuildCall(self.builder, function, args.as_mut_ptr(), args.len() as u32, c_string.as_ptr())
         };
 
         if tail_call {
@@ -93,7 +138,100 @@ impl Builder {
         }
     }
 
+    /// Builds an `invoke` terminator, which calls `function` and continues in `then_block`
+    /// if it returns normally or in `catch_block` if it unwinds. `catch_block` must begin
+    /// with a landingpad, see `build_landing_pad`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::AddressSpace;
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let void_type = context.void_type();
+    /// let i8_ptr_type = context.i8_type().ptr_type(AddressSpace::Generic);
+    /// let exception_type = context.struct_type(&[i8_ptr_type.into(), context.i32_type().into()], false);
+    /// let personality_type = context.i32_type().fn_type(&[], true);
+    /// let personality = module.add_function("__gxx_personality_v0", &personality_type, None);
+    /// let fn_type = void_type.fn_type(&[], false);
+    /// let may_throw = module.add_function("may_throw", &fn_type, None);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    /// let entry = fn_value.append_basic_block("entry");
+    /// let cont = fn_value.append_basic_block("cont");
+    /// let lpad = fn_value.append_basic_block("lpad");
+    ///
+    /// builder.position_at_end(&entry);
+    /// builder.build_invoke(may_throw, &[], &cont, &lpad, "");
+    ///
+    /// builder.position_at_end(&cont);
+    /// builder.build_return(None);
+    ///
+    /// builder.position_at_end(&lpad);
+    ///
+    /// let exception = builder.build_landing_pad(exception_type, &personality, 0, "exception");
+    ///
+    /// exception.as_instruction().unwrap().set_cleanup(true);
+    /// builder.build_resume(exception);
+    /// ```
+    pub fn build_invoke(&self, function: FunctionValue, args: &[BasicValueEnum], then_block: &BasicBlock, catch_block: &BasicBlock, name: &str) -> Either<BasicValueEnum, InstructionValue> {
+        // LLVM gets upset when void calls are named because they don't return anything
+        let name = unsafe {
+            match LLVMGetTypeKind(LLVMGetReturnType(LLVMGetElementType(LLVMTypeOf(function.as_value_ref())))) {
+                LLVMTypeKind::LLVMVoidTypeKind => "",
+                _ => name,
+            }
+        };
+
+        let c_string = CString::new(name).expect("Conversion to CString failed unexpectedly");
+        let mut args: Vec<LLVMValueRef> = args.iter()
+                                              .map(|val| val.as_value_ref())
+                                              .collect();
+        let value = unsafe {
+            LLVMBuildInvoke(self.builder, function.as_value_ref(), args.as_mut_ptr(), args.len() as u32, then_block.basic_block, catch_block.basic_block, c_string.as_ptr())
+        };
+
+        unsafe {
+            match LLVMGetTypeKind(LLVMTypeOf(value)) {
+                LLVMTypeKind::LLVMVoidTypeKind => Either::Right(InstructionValue::new(value)),
+                _ => Either::Left(BasicValueEnum::new(value)),
+            }
+        }
+    }
+
+    /// Builds a `landingpad` instruction of `exception_type`, usually `{ i8*, i32 }`, which
+    /// must be the first instruction of an `invoke`'s catch block. `num_clauses` reserves
+    /// space for clauses which are then added with `InstructionValue::add_clause`. A landingpad
+    /// without clauses must be marked as a cleanup with `InstructionValue::set_cleanup`.
+    ///
+    /// The `personality_function` is also set as the enclosing function's personality.
+    pub fn build_landing_pad<T: BasicType>(&self, exception_type: T, personality_function: &FunctionValue, num_clauses: u32, name: &str) -> BasicValueEnum {
+        let c_string = CString::new(name).expect("Conversion to CString failed unexpectedly");
+
+        let value = unsafe {
+            LLVMBuildLandingPad(self.builder, exception_type.as_type_ref(), personality_function.as_value_ref(), num_clauses, c_string.as_ptr())
+        };
+
+        BasicValueEnum::new(value)
+    }
+
+    /// Builds a `resume` terminator, which continues unwinding an in-flight exception
+    /// given the value produced by its landingpad.
+    pub fn build_resume<V: BasicValue>(&self, value: V) -> InstructionValue {
+        let val = unsafe {
+            LLVMBuildResume(self.builder, value.as_value_ref())
+        };
+
+        InstructionValue::new(val)
+    }
+
     // REVIEW: Doesn't GEP work on array too?
+    /// Builds a `getelementptr` instruction, which computes the address of an element
+    /// within the aggregate `ptr` points to, without accessing memory. The first index
+    /// steps over `ptr` itself, and each further index steps into the aggregate.
+    ///
     /// GEP is very likely to segfault if indexes are used incorrectly, and is therefore an unsafe function. Maybe we can change this in the future.
     pub unsafe fn build_gep(&self, ptr: PointerValue, ordered_indexes: &[IntValue], name: &str) -> PointerValue {
         let c_string = CString::new(name).expect("Conversion to CString failed unexpectedly");
@@ -107,6 +245,10 @@ impl Builder {
     }
 
     // REVIEW: Doesn't GEP work on array too?
+    /// Builds a `getelementptr inbounds` instruction. This is the same as `build_gep`, except
+    /// that the result is a poison value if the computed address falls outside of the object
+    /// `ptr` points into, which allows for better optimization.
+    ///
     /// GEP is very likely to segfault if indexes are used incorrectly, and is therefore an unsafe function. Maybe we can change this in the future.
     pub unsafe fn build_in_bounds_gep(&self, ptr: PointerValue, ordered_indexes: &[IntValue], name: &str) -> PointerValue {
         let c_string = CString::new(name).expect("Conversion to CString failed unexpectedly");
@@ -119,14 +261,55 @@ impl Builder {
         PointerValue::new(value)
     }
 
+    /// Builds a `getelementptr inbounds` instruction which computes a pointer to the field at
+    /// `index` of the struct `ptr` points to.
+    ///
+    /// An error is returned if `ptr` does not point to a struct, or if `index` is out of bounds
+    /// for that struct's fields.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::AddressSpace;
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let i64_type = context.i64_type();
+    /// let struct_type = context.struct_type(&[i32_type.into(), i64_type.into()], false);
+    /// let struct_ptr_type = struct_type.ptr_type(AddressSpace::Generic);
+    /// let fn_type = context.void_type().fn_type(&[struct_ptr_type.into()], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    /// let entry = fn_value.append_basic_block("entry");
+    ///
+    /// builder.position_at_end(&entry);
+    ///
+    /// let struct_ptr = fn_value.get_first_param().unwrap().into_pointer_value();
+    /// let field_ptr = builder.build_struct_gep(struct_ptr, 1, "field_ptr").unwrap();
+    ///
+    /// assert_eq!(field_ptr.get_type().get_element_type().into_int_type(), i64_type);
+    /// assert!(builder.build_struct_gep(struct_ptr, 2, "out_of_bounds").is_err());
+    /// ```
     // REVIEW: Shouldn't this take a StructValue? Or does it still need to be PointerValue<StructValue>?
-    /// GEP is very likely to segfault if indexes are used incorrectly, and is therefore an unsafe function. Maybe we can change this in the future.
-    pub unsafe fn build_struct_gep(&self, ptr: PointerValue, index: u32, name: &str) -> PointerValue {
+    pub fn build_struct_gep(&self, ptr: PointerValue, index: u32, name: &str) -> Result<PointerValue, &'static str> {
+        let struct_type = match ptr.get_type().get_element_type() {
+            AnyTypeEnum::StructType(struct_type) => struct_type,
+            _ => return Err("Pointer must point to a struct."),
+        };
+
+        if index >= struct_type.count_fields() {
+            return Err("Field index is out of bounds for the struct.");
+        }
+
         let c_string = CString::new(name).expect("Conversion to CString failed unexpectedly");
 
-        let value = LLVMBuildStructGEP(self.builder, ptr.as_value_ref(), index, c_string.as_ptr());
+        let value = unsafe {
+            LLVMBuildStructGEP(self.builder, ptr.as_value_ref(), index, c_string.as_ptr())
+        };
 
-        PointerValue::new(value)
+        Ok(PointerValue::new(value))
     }
 
     pub fn build_ptr_diff(&self, lhs_ptr: PointerValue, rhs_ptr: PointerValue, name: &str) -> IntValue {
@@ -139,6 +322,9 @@ impl Builder {
         IntValue::new(value)
     }
 
+    /// Builds an empty `phi` node of the given type, which selects a value based on the
+    /// predecessor block control flow arrived from. Phis must be grouped at the start of
+    /// their block, and incoming values are added with `PhiValue::add_incoming`.
     pub fn build_phi<T: BasicType>(&self, type_: T, name: &str) -> PhiValue {
         let c_string = CString::new(name).expect("Conversion to CString failed unexpectedly");
 
@@ -167,6 +353,229 @@ impl Builder {
         BasicValueEnum::new(value)
     }
 
+    /// Builds an atomic store of `value` to `ptr` with the given `AtomicOrdering`.
+    /// Stores may not use `Acquire` or `AcquireRelease` ordering, in which case an
+    /// error is returned.
+    ///
+    /// LLVM requires atomic stores to have an explicit alignment, which can be
+    /// set with `InstructionValue::set_alignment`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::{AddressSpace, AtomicOrdering};
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let i32_ptr_type = i32_type.ptr_type(AddressSpace::Generic);
+    /// let fn_type = context.void_type().fn_type(&[i32_ptr_type.into()], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    /// let entry = fn_value.append_basic_block("entry");
+    ///
+    /// builder.position_at_end(&entry);
+    ///
+    /// let ptr = fn_value.get_first_param().unwrap().into_pointer_value();
+    /// let store = builder.build_store_atomic(ptr, i32_type.const_int(7, false), AtomicOrdering::Release).unwrap();
+    ///
+    /// store.set_alignment(4);
+    ///
+    /// assert!(builder.build_store_atomic(ptr, i32_type.const_int(7, false), AtomicOrdering::Acquire).is_err());
+    /// ```
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+    pub fn build_store_atomic<V: BasicValue>(&self, ptr: PointerValue, value: V, ordering: AtomicOrdering) -> Result<InstructionValue, &'static str> {
+        match ordering {
+            AtomicOrdering::Acquire | AtomicOrdering::AcquireRelease => return Err("Atomic stores may not have acquire ordering."),
+            _ => (),
+        }
+
+        let store = self.build_store(ptr, value);
+
+        unsafe {
+            LLVMSetOrdering(store.as_value_ref(), ordering.as_llvm_ordering());
+        }
+
+        Ok(store)
+    }
+
+    /// Builds an atomic load from `ptr` with the given `AtomicOrdering`.
+    /// Loads may not use `Release` or `AcquireRelease` ordering, in which case an
+    /// error is returned.
+    ///
+    /// LLVM requires atomic loads to have an explicit alignment, which can be
+    /// set with `InstructionValue::set_alignment`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::{AddressSpace, AtomicOrdering};
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let i32_ptr_type = i32_type.ptr_type(AddressSpace::Generic);
+    /// let fn_type = i32_type.fn_type(&[i32_ptr_type.into()], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    /// let entry = fn_value.append_basic_block("entry");
+    ///
+    /// builder.position_at_end(&entry);
+    ///
+    /// let ptr = fn_value.get_first_param().unwrap().into_pointer_value();
+    /// let value = builder.build_load_atomic(ptr, AtomicOrdering::Acquire, "value").unwrap();
+    ///
+    /// value.as_instruction().unwrap().set_alignment(4);
+    ///
+    /// builder.build_return(Some(&value));
+    /// ```
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+    pub fn build_load_atomic(&self, ptr: PointerValue, ordering: AtomicOrdering, name: &str) -> Result<BasicValueEnum, &'static str> {
+        match ordering {
+            AtomicOrdering::Release | AtomicOrdering::AcquireRelease => return Err("Atomic loads may not have release ordering."),
+            _ => (),
+        }
+
+        let value = self.build_load(ptr, name);
+
+        unsafe {
+            LLVMSetOrdering(value.as_value_ref(), ordering.as_llvm_ordering());
+        }
+
+        Ok(value)
+    }
+
+    /// Builds an `atomicrmw` instruction, which atomically applies `op` to the integer
+    /// at `ptr` and `value`, stores the result back to `ptr`, and returns the value
+    /// that was previously in memory.
+    ///
+    /// An error is returned if `ptr` does not point to `value`'s type, if that type is
+    /// not at least 8 bits wide and a power of two, or if the ordering is `Unordered`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::{AtomicOrdering, AtomicRMWBinOp};
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let counter = module.add_global(i32_type, None, "counter");
+    /// let fn_type = i32_type.fn_type(&[], false);
+    /// let fn_value = module.add_function("increment", &fn_type, None);
+    /// let entry = fn_value.append_basic_block("entry");
+    ///
+    /// builder.position_at_end(&entry);
+    ///
+    /// let one = i32_type.const_int(1, false);
+    /// let old = builder.build_atomicrmw(AtomicRMWBinOp::Add, counter.as_pointer_value(), one, AtomicOrdering::SequentiallyConsistent).unwrap();
+    ///
+    /// builder.build_return(Some(&old));
+    /// ```
+    pub fn build_atomicrmw(&self, op: AtomicRMWBinOp, ptr: PointerValue, value: IntValue, ordering: AtomicOrdering) -> Result<IntValue, &'static str> {
+        if ptr.get_type().get_element_type() != value.get_type().as_any_type_enum() {
+            return Err("Pointer's pointee type must match the value's type.");
+        }
+
+        let bit_width = value.get_type().get_bit_width();
+
+        if bit_width < 8 || !bit_width.is_power_of_two() {
+            return Err("The value must be at least 8 bits wide and a power of two.");
+        }
+
+        if ordering == AtomicOrdering::Unordered {
+            return Err("atomicrmw instructions may not be unordered.");
+        }
+
+        let value = unsafe {
+            LLVMBuildAtomicRMW(self.builder, op.as_llvm_bin_op(), ptr.as_value_ref(), value.as_value_ref(), ordering.as_llvm_ordering(), false as i32)
+        };
+
+        Ok(IntValue::new(value))
+    }
+
+    /// Builds a `cmpxchg` instruction, which atomically compares the value at `ptr`
+    /// with `cmp` and, if they are equal, stores `new` to `ptr`. The result is a
+    /// `{ value, i1 }` struct holding the value previously in memory and whether
+    /// the exchange succeeded.
+    ///
+    /// An error is returned if `cmp` and `new` are not integers or pointers of the
+    /// type `ptr` points to, if either ordering is `Unordered`, if the failure ordering
+    /// is `Release` or `AcquireRelease`, or if the failure ordering is stronger than
+    /// the success ordering.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::{AddressSpace, AtomicOrdering};
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let i32_ptr_type = i32_type.ptr_type(AddressSpace::Generic);
+    /// let fn_type = context.bool_type().fn_type(&[i32_ptr_type.into()], false);
+    /// let fn_value = module.add_function("try_lock", &fn_type, None);
+    /// let entry = fn_value.append_basic_block("entry");
+    ///
+    /// builder.position_at_end(&entry);
+    ///
+    /// let ptr = fn_value.get_first_param().unwrap().into_pointer_value();
+    /// let unlocked = i32_type.const_int(0, false);
+    /// let locked = i32_type.const_int(1, false);
+    /// let result = builder.build_cmpxchg(ptr, unlocked, locked, AtomicOrdering::AcquireRelease, AtomicOrdering::Monotonic).unwrap();
+    /// let success = builder.build_extract_value(result, 1, "success").unwrap();
+    ///
+    /// builder.build_return(Some(&success));
+    /// ```
+    pub fn build_cmpxchg<V: BasicValue>(&self, ptr: PointerValue, cmp: V, new: V, success: AtomicOrdering, failure: AtomicOrdering) -> Result<StructValue, &'static str> {
+        let cmp = cmp.as_basic_value_enum();
+        let new = new.as_basic_value_enum();
+
+        if cmp.get_type() != new.get_type() {
+            return Err("The compared and new values must have the same type.");
+        }
+
+        if ptr.get_type().get_element_type() != cmp.get_type().as_any_type_enum() {
+            return Err("Pointer's pointee type must match the value's type.");
+        }
+
+        match cmp {
+            BasicValueEnum::IntValue(int_value) => {
+                let bit_width = int_value.get_type().get_bit_width();
+
+                if bit_width < 8 || !bit_width.is_power_of_two() {
+                    return Err("The value must be at least 8 bits wide and a power of two.");
+                }
+            },
+            BasicValueEnum::PointerValue(_) => (),
+            _ => return Err("The value must be an integer or a pointer."),
+        }
+
+        if success == AtomicOrdering::Unordered || failure == AtomicOrdering::Unordered {
+            return Err("cmpxchg instructions may not be unordered.");
+        }
+
+        if failure == AtomicOrdering::Release || failure == AtomicOrdering::AcquireRelease {
+            return Err("The failure ordering may not have release semantics.");
+        }
+
+        if failure.is_stronger_than(success) {
+            return Err("The failure ordering may not be stronger than the success ordering.");
+        }
+
+        let value = unsafe {
+            LLVMBuildAtomicCmpXchg(self.builder, ptr.as_value_ref(), cmp.as_value_ref(), new.as_value_ref(), success.as_llvm_ordering(), failure.as_llvm_ordering(), false as i32)
+        };
+
+        Ok(StructValue::new(value))
+    }
+
     // TODOC: Stack allocation
     pub fn build_alloca<T: BasicType>(&self, ty: T, name: &str) -> PointerValue {
         let c_string = CString::new(name).expect("Conversion to CString failed unexpectedly");
@@ -235,6 +644,26 @@ impl Builder {
         }
     }
 
+    /// Gets the `BasicBlock` this `Builder` is currently positioned in, if any.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    /// let entry = fn_value.append_basic_block("entry");
+    ///
+    /// assert!(builder.get_insert_block().is_none());
+    ///
+    /// builder.position_at_end(&entry);
+    ///
+    /// assert_eq!(builder.get_insert_block().unwrap(), entry);
+    /// ```
     pub fn get_insert_block(&self) -> Option<BasicBlock> {
         let bb = unsafe {
             LLVMGetInsertBlock(self.builder)
@@ -785,6 +1214,78 @@ impl Builder {
         T::new(value)
     }
 
+    /// Builds a `bitcast` instruction, which reinterprets the bits of `value` as `to_type`
+    /// without changing them. Both types must have the same size, and pointers may only be
+    /// cast to other pointers in the same address space.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let f32_type = context.f32_type();
+    /// let i32_type = context.i32_type();
+    /// let fn_type = i32_type.fn_type(&[f32_type.into()], false);
+    /// let fn_value = module.add_function("float_bits", &fn_type, None);
+    /// let entry = fn_value.append_basic_block("entry");
+    ///
+    /// builder.position_at_end(&entry);
+    ///
+    /// let float = fn_value.get_first_param().unwrap();
+    /// let bits = builder.build_bitcast(float, i32_type, "bits");
+    ///
+    /// builder.build_return(Some(&bits));
+    /// ```
+    pub fn build_bitcast<T: BasicType, V: BasicValue>(&self, value: V, to_type: T, name: &str) -> BasicValueEnum {
+        let c_string = CString::new(name).expect("Conversion to CString failed unexpectedly");
+
+        let value = unsafe {
+            LLVMBuildBitCast(self.builder, value.as_value_ref(), to_type.as_type_ref(), c_string.as_ptr())
+        };
+
+        BasicValueEnum::new(value)
+    }
+
+    /// Builds an `addrspacecast` instruction, which converts a pointer into a pointer
+    /// in a different `AddressSpace`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::AddressSpace;
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let global_ptr_type = i32_type.ptr_type(AddressSpace::Global);
+    /// let generic_ptr_type = i32_type.ptr_type(AddressSpace::Generic);
+    /// let fn_type = generic_ptr_type.fn_type(&[global_ptr_type.into()], false);
+    /// let fn_value = module.add_function("to_generic", &fn_type, None);
+    /// let entry = fn_value.append_basic_block("entry");
+    ///
+    /// builder.position_at_end(&entry);
+    ///
+    /// let global_ptr = fn_value.get_first_param().unwrap().into_pointer_value();
+    /// let generic_ptr = builder.build_address_space_cast(global_ptr, generic_ptr_type, "generic_ptr");
+    ///
+    /// builder.build_return(Some(&generic_ptr));
+    /// ```
+    // SubType: <F, T>(&self, from: &PointerValue<F>, to: &PointerType<T>, name: &str) -> PointerValue<T> {
+    pub fn build_address_space_cast<T: PointerMathValue>(&self, from: T, to: T::BaseType, name: &str) -> T {
+        let c_string = CString::new(name).expect("Conversion to CString failed unexpectedly");
+
+        let value = unsafe {
+            LLVMBuildAddrSpaceCast(self.builder, from.as_value_ref(), to.as_type_ref(), c_string.as_ptr())
+        };
+
+        T::new(value)
+    }
+
     // SubType: <I>(&self, op, lhs: &IntValue<I>, rhs: &IntValue<I>, name) -> IntValue<bool> { ?
     // Note: we need a way to get an appropriate return type, since this method's return value
     // is always a bool (or vector of bools), not necessarily the same as the input value
@@ -883,6 +1384,8 @@ impl Builder {
         T::new(value)
     }
 
+    /// Positions this `Builder` in the given `BasicBlock` so that new instructions
+    /// are inserted before `instruction`.
     // REVIEW: What if instruction and basic_block are completely unrelated?
     // It'd be great if we could get the BB from the instruction behind the scenes
     pub fn position_at(&self, basic_block: &BasicBlock, instruction: &InstructionValue) {
@@ -891,42 +1394,159 @@ impl Builder {
         }
     }
 
+    /// Positions this `Builder` so that new instructions are inserted immediately
+    /// before `instruction`, in whichever `BasicBlock` contains it.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    /// let entry = fn_value.append_basic_block("entry");
+    ///
+    /// builder.position_at_end(&entry);
+    ///
+    /// let ret = builder.build_return(None);
+    ///
+    /// builder.position_before(&ret);
+    /// builder.build_unreachable();
+    ///
+    /// assert_eq!(entry.get_last_instruction().unwrap(), ret);
+    /// ```
     pub fn position_before(&self, instruction: &InstructionValue) {
         unsafe {
             LLVMPositionBuilderBefore(self.builder, instruction.as_value_ref())
         }
     }
 
+    /// Positions this `Builder` at the end of the given `BasicBlock`, so that new
+    /// instructions are appended to it.
     pub fn position_at_end(&self, basic_block: &BasicBlock) {
         unsafe {
             LLVMPositionBuilderAtEnd(self.builder, basic_block.basic_block);
         }
     }
 
-    // REVIEW: How does LLVM treat out of bound index? Maybe we should return an Option?
-    // or is that only in bounds GEP
-    // REVIEW: Should this be AggregatePointerValue?
-    pub fn build_extract_value(&self, value: &AggregateValue, index: u32, name: &str) -> BasicValueEnum {
+    /// Builds an `extractvalue` instruction, which gets the element at `index` of a
+    /// struct or array value. Returns `None` if `index` is out of bounds for the
+    /// aggregate's type.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let i8_type = context.i8_type();
+    /// let struct_type = context.struct_type(&[i32_type.into(), i8_type.into()], false);
+    /// let fn_type = i8_type.fn_type(&[struct_type.into()], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    /// let entry = fn_value.append_basic_block("entry");
+    ///
+    /// builder.position_at_end(&entry);
+    ///
+    /// let struct_value = fn_value.get_first_param().unwrap().into_struct_value();
+    ///
+    /// assert!(builder.build_extract_value(struct_value, 2, "out_of_bounds").is_none());
+    ///
+    /// let field = builder.build_extract_value(struct_value, 1, "field").unwrap();
+    ///
+    /// builder.build_return(Some(&field));
+    /// ```
+    pub fn build_extract_value<AV: AggregateValue>(&self, agg: AV, index: u32, name: &str) -> Option<BasicValueEnum> {
+        if index >= Self::aggregate_len(&agg) {
+            return None;
+        }
+
         let c_string = CString::new(name).expect("Conversion to CString failed unexpectedly");
 
         let value = unsafe {
-            LLVMBuildExtractValue(self.builder, value.as_value_ref(), index, c_string.as_ptr())
+            LLVMBuildExtractValue(self.builder, agg.as_value_ref(), index, c_string.as_ptr())
         };
 
-        BasicValueEnum::new(value)
+        Some(BasicValueEnum::new(value))
     }
 
-    // REVIEW: Should this be AggregatePointerValue instead of just PointerValue?
-    pub fn build_insert_value<V: BasicValue>(&self, value: V, ptr: PointerValue, index: u32, name: &str) -> InstructionValue {
+    /// Builds an `insertvalue` instruction, which produces a copy of a struct or array
+    /// value with the element at `index` replaced by `value`. Returns `None` if `index`
+    /// is out of bounds for the aggregate's type.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let array_type = i32_type.array_type(2);
+    /// let fn_type = array_type.fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    /// let entry = fn_value.append_basic_block("entry");
+    ///
+    /// builder.position_at_end(&entry);
+    ///
+    /// let array = array_type.get_undef();
+    /// let array = builder.build_insert_value(array, i32_type.const_int(1, false), 0, "array").unwrap();
+    /// let array = builder.build_insert_value(array, i32_type.const_int(2, false), 1, "array").unwrap();
+    ///
+    /// assert!(builder.build_insert_value(array, i32_type.const_int(3, false), 2, "out_of_bounds").is_none());
+    ///
+    /// builder.build_return(Some(&array));
+    /// ```
+    pub fn build_insert_value<AV: AggregateValue, BV: BasicValue>(&self, agg: AV, value: BV, index: u32, name: &str) -> Option<AggregateValueEnum> {
+        if index >= Self::aggregate_len(&agg) {
+            return None;
+        }
+
         let c_string = CString::new(name).expect("Conversion to CString failed unexpectedly");
 
         let value = unsafe {
-            LLVMBuildInsertValue(self.builder, value.as_value_ref(), ptr.as_value_ref(), index, c_string.as_ptr())
+            LLVMBuildInsertValue(self.builder, agg.as_value_ref(), value.as_value_ref(), index, c_string.as_ptr())
         };
 
-        InstructionValue::new(value)
+        Some(AggregateValueEnum::new(value))
     }
 
+    fn aggregate_len<AV: AggregateValue>(agg: &AV) -> u32 {
+        match agg.as_aggregate_value_enum() {
+            AggregateValueEnum::ArrayValue(array_value) => array_value.get_type().len(),
+            AggregateValueEnum::StructValue(struct_value) => struct_value.get_type().count_fields(),
+        }
+    }
+
+    /// Builds an `extractelement` instruction, which gets the element of `vector` at `index`.
+    /// An out of bounds index produces a poison value.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let fn_type = i32_type.fn_type(&[i32_type.vec_type(4).into()], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    /// let entry = fn_value.append_basic_block("entry");
+    ///
+    /// builder.position_at_end(&entry);
+    ///
+    /// let vector = fn_value.get_first_param().unwrap().into_vector_value();
+    /// let element = builder.build_extract_element(vector, i32_type.const_int(2, false), "element");
+    ///
+    /// builder.build_return(Some(&element));
+    /// ```
     pub fn build_extract_element(&self, vector: VectorValue, index: IntValue, name: &str) -> BasicValueEnum {
         let c_string = CString::new(name).expect("Conversion to CString failed unexpectedly");
 
@@ -937,16 +1557,82 @@ impl Builder {
         BasicValueEnum::new(value)
     }
 
-    pub fn build_insert_element<V: BasicValue>(&self, vector: VectorValue, element: V, index: IntValue, name: &str) -> BasicValueEnum {
+    /// Builds an `insertelement` instruction, which produces a copy of `vector` with the
+    /// element at `index` replaced by `element`. An out of bounds index produces a poison value.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::types::BasicType;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let i32_vec_type = i32_type.vec_type(4);
+    /// let fn_type = i32_vec_type.fn_type(&[i32_vec_type.into()], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    /// let entry = fn_value.append_basic_block("entry");
+    ///
+    /// builder.position_at_end(&entry);
+    ///
+    /// let vector = fn_value.get_first_param().unwrap().into_vector_value();
+    /// let vector = builder.build_insert_element(vector, i32_type.const_int(7, false), i32_type.const_int(0, false), "vector");
+    ///
+    /// builder.build_return(Some(&vector));
+    /// ```
+    pub fn build_insert_element<V: BasicValue>(&self, vector: VectorValue, element: V, index: IntValue, name: &str) -> VectorValue {
         let c_string = CString::new(name).expect("Conversion to CString failed unexpectedly");
 
         let value = unsafe {
             LLVMBuildInsertElement(self.builder, vector.as_value_ref(), element.as_value_ref(), index.as_value_ref(), c_string.as_ptr())
         };
 
-        BasicValueEnum::new(value)
+        VectorValue::new(value)
     }
 
+    /// Builds a `shufflevector` instruction, which produces a vector of the same length as
+    /// `mask` from elements of `left` and `right`. `mask` must be a constant vector of `i32`s,
+    /// where indices below the length of `left` select from `left` and the remaining indices
+    /// select from `right`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::types::{BasicType, VectorType};
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let i32_vec_type = i32_type.vec_type(2);
+    /// let fn_type = i32_vec_type.fn_type(&[i32_vec_type.into(), i32_vec_type.into()], false);
+    /// let fn_value = module.add_function("interleave_low", &fn_type, None);
+    /// let entry = fn_value.append_basic_block("entry");
+    ///
+    /// builder.position_at_end(&entry);
+    ///
+    /// let left = fn_value.get_first_param().unwrap().into_vector_value();
+    /// let right = fn_value.get_last_param().unwrap().into_vector_value();
+    /// let mask = VectorType::const_vector(&[i32_type.const_int(0, false), i32_type.const_int(2, false)]);
+    /// let shuffled = builder.build_shuffle_vector(left, right, mask, "shuffled");
+    ///
+    /// builder.build_return(Some(&shuffled));
+    /// ```
+    pub fn build_shuffle_vector(&self, left: VectorValue, right: VectorValue, mask: VectorValue, name: &str) -> VectorValue {
+        let c_string = CString::new(name).expect("Conversion to CString failed unexpectedly");
+
+        let value = unsafe {
+            LLVMBuildShuffleVector(self.builder, left.as_value_ref(), right.as_value_ref(), mask.as_value_ref(), c_string.as_ptr())
+        };
+
+        VectorValue::new(value)
+    }
+
+    /// Builds an `unreachable` terminator, which tells the optimizer that control flow
+    /// can never reach this point, such as after a call to a function which never returns.
     pub fn build_unreachable(&self) -> InstructionValue {
         let val = unsafe {
             LLVMBuildUnreachable(self.builder)
@@ -955,16 +1641,44 @@ impl Builder {
         InstructionValue::new(val)
     }
 
-    // REVIEW: Not sure if this should return InstructionValue or an actual value
-    // TODO: Better name for num?
-    pub fn build_fence(&self, atmoic_ordering: LLVMAtomicOrdering, num: i32, name: &str) -> InstructionValue {
+    /// Builds a `fence` instruction, which orders memory operations around it
+    /// according to `atomic_ordering`. Only `Acquire`, `Release`, `AcquireRelease`
+    /// and `SequentiallyConsistent` are valid orderings for a fence, and an error is
+    /// returned for any other. If `singlethread` is true, the fence only synchronizes
+    /// with code running on the same thread, such as signal handlers.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::AtomicOrdering;
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    /// let entry = fn_value.append_basic_block("entry");
+    ///
+    /// builder.position_at_end(&entry);
+    /// builder.build_fence(AtomicOrdering::SequentiallyConsistent, false, "").unwrap();
+    /// builder.build_return(None);
+    ///
+    /// assert!(builder.build_fence(AtomicOrdering::Monotonic, false, "").is_err());
+    /// ```
+    pub fn build_fence(&self, atomic_ordering: AtomicOrdering, singlethread: bool, name: &str) -> Result<InstructionValue, &'static str> {
+        match atomic_ordering {
+            AtomicOrdering::Unordered | AtomicOrdering::Monotonic => return Err("Fences must have acquire, release or sequentially consistent ordering."),
+            _ => (),
+        }
+
         let c_string = CString::new(name).expect("Conversion to CString failed unexpectedly");
 
         let val = unsafe {
-            LLVMBuildFence(self.builder, atmoic_ordering, num, c_string.as_ptr())
+            LLVMBuildFence(self.builder, atomic_ordering.as_llvm_ordering(), singlethread as i32, c_string.as_ptr())
         };
 
-        InstructionValue::new(val)
+        Ok(InstructionValue::new(val))
     }
 
     // SubType: <P>(&self, ptr: &PointerValue<P>, name) -> IntValue<bool> {
@@ -1011,12 +1725,39 @@ impl Builder {
         <<T::BaseType as PointerMathType>::PtrConvType as IntMathType>::ValueType::new(value)
     }
 
+    /// Clears this `Builder`'s position, after which `get_insert_block` returns `None`
+    /// until the `Builder` is positioned again.
     pub fn clear_insertion_position(&self) {
         unsafe {
             LLVMClearInsertionPosition(self.builder)
         }
     }
 
+    /// Builds a `switch` terminator, which branches to the `BasicBlock` of the first case
+    /// whose value equals `value`, or to `else_block` if none do. Case values should be
+    /// distinct integer constants of the same type as `value`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let fn_type = context.void_type().fn_type(&[i32_type.into()], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    /// let entry = fn_value.append_basic_block("entry");
+    /// let one = fn_value.append_basic_block("one");
+    /// let other = fn_value.append_basic_block("other");
+    ///
+    /// builder.position_at_end(&entry);
+    ///
+    /// let value = fn_value.get_first_param().unwrap().into_int_value();
+    ///
+    /// builder.build_switch(&value, &other, &[(&i32_type.const_int(1, false), &one)]);
+    /// ```
     // REVIEW: Returning InstructionValue is the safe move here; but if the value means something
     // (IE the result of the switch) it should probably return BasicValueEnum?
     // SubTypes: I think value and case values must be the same subtype (maybe). Case value might need to be constants
@@ -1054,6 +1795,44 @@ impl Builder {
 
         GlobalValue::new(value)
     }
+
+    /// Builds a `select` instruction, which evaluates to `then_value` if `condition` is true
+    /// and to `else_value` otherwise. `condition` may be either an `i1` or, when selecting
+    /// between vectors, a vector of `i1`s of the same length which selects per element.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::IntPredicate;
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let fn_type = i32_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+    /// let fn_value = module.add_function("max", &fn_type, None);
+    /// let entry = fn_value.append_basic_block("entry");
+    ///
+    /// builder.position_at_end(&entry);
+    ///
+    /// let a = fn_value.get_first_param().unwrap().into_int_value();
+    /// let b = fn_value.get_last_param().unwrap().into_int_value();
+    /// let a_is_greater = builder.build_int_compare(IntPredicate::SGT, a, b, "a_is_greater");
+    /// let max = builder.build_select(a_is_greater, a, b, "max");
+    ///
+    /// builder.build_return(Some(&max));
+    /// ```
+    // SubTypes: condition should be IntValue<bool> or VectorValue<IntValue<bool>>
+    pub fn build_select<BV: BasicValue, IMV: IntMathValue>(&self, condition: IMV, then_value: BV, else_value: BV, name: &str) -> BasicValueEnum {
+        let c_string = CString::new(name).expect("Conversion to CString failed unexpectedly");
+
+        let value = unsafe {
+            LLVMBuildSelect(self.builder, condition.as_value_ref(), then_value.as_value_ref(), else_value.as_value_ref(), c_string.as_ptr())
+        };
+
+        BasicValueEnum::new(value)
+    }
 }
 
 impl Drop for Builder {
@@ -1,16 +1,22 @@
 //! A `Context` is an opaque owner and manager of core global data.
 
-use llvm_sys::core::{LLVMAppendBasicBlockInContext, LLVMContextCreate, LLVMContextDispose, LLVMCreateBuilderInContext, LLVMDoubleTypeInContext, LLVMFloatTypeInContext, LLVMFP128TypeInContext, LLVMInsertBasicBlockInContext, LLVMInt16TypeInContext, LLVMInt1TypeInContext, LLVMInt32TypeInContext, LLVMInt64TypeInContext, LLVMInt8TypeInContext, LLVMIntTypeInContext, LLVMModuleCreateWithNameInContext, LLVMStructCreateNamed, LLVMStructTypeInContext, LLVMVoidTypeInContext, LLVMHalfTypeInContext, LLVMGetGlobalContext, LLVMPPCFP128TypeInContext, LLVMConstStructInContext, LLVMMDNodeInContext, LLVMMDStringInContext, LLVMGetMDKindIDInContext, LLVMX86FP80TypeInContext};
+use libc::c_void;
+use llvm_sys::core::{LLVMAppendBasicBlockInContext, LLVMContextCreate, LLVMContextDispose, LLVMCreateBuilderInContext, LLVMDoubleTypeInContext, LLVMFloatTypeInContext, LLVMFP128TypeInContext, LLVMInsertBasicBlockInContext, LLVMInt16TypeInContext, LLVMInt1TypeInContext, LLVMInt32TypeInContext, LLVMInt64TypeInContext, LLVMInt8TypeInContext, LLVMIntTypeInContext, LLVMModuleCreateWithNameInContext, LLVMStructCreateNamed, LLVMStructTypeInContext, LLVMVoidTypeInContext, LLVMHalfTypeInContext, LLVMGetGlobalContext, LLVMPPCFP128TypeInContext, LLVMConstStructInContext, LLVMConstStringInContext, LLVMMDNodeInContext, LLVMMDStringInContext, LLVMGetMDKindIDInContext, LLVMX86FP80TypeInContext, LLVMContextSetDiagnosticHandler, LLVMConstInlineAsm, LLVMLabelTypeInContext};
+use llvm_sys::LLVMDiagnosticHandler;
 use llvm_sys::prelude::{LLVMContextRef, LLVMTypeRef, LLVMValueRef};
 use llvm_sys::ir_reader::LLVMParseIRInContext;
 
+#[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+use attributes::Attribute;
 use basic_block::BasicBlock;
 use builder::Builder;
 use memory_buffer::MemoryBuffer;
 use module::Module;
 use support::LLVMString;
-use types::{BasicTypeEnum, FloatType, IntType, StructType, VoidType, AsTypeRef};
-use values::{AsValueRef, FunctionValue, StructValue, MetadataValue, BasicValueEnum};
+use types::{BasicTypeEnum, FloatType, FunctionType, IntType, LabelType, StructType, VoidType, AsTypeRef};
+#[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8", feature = "llvm3-9", feature = "llvm4-0", feature = "llvm5-0")))]
+use types::{MetadataType, TokenType};
+use values::{AsValueRef, ArrayValue, FunctionValue, PointerValue, StructValue, MetadataValue, BasicValueEnum};
 
 use std::ffi::CString;
 use std::mem::forget;
@@ -171,6 +177,75 @@ impl Context {
         VoidType::new(void_type)
     }
 
+    /// Gets the `LabelType`, which is the type of a `BasicBlock` used as a value.
+    /// It will be assigned the current context.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let label_type = context.label_type();
+    ///
+    /// assert_eq!(*label_type.get_context(), context);
+    /// ```
+    pub fn label_type(&self) -> LabelType {
+        let label_type = unsafe {
+            LLVMLabelTypeInContext(*self.context)
+        };
+
+        LabelType::new(label_type)
+    }
+
+    /// Gets the `MetadataType`, which is the type of metadata arguments to intrinsics.
+    /// It will be assigned the current context.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let metadata_type = context.metadata_type();
+    ///
+    /// assert_eq!(*metadata_type.get_context(), context);
+    /// ```
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8", feature = "llvm3-9", feature = "llvm4-0", feature = "llvm5-0")))]
+    pub fn metadata_type(&self) -> MetadataType {
+        use llvm_sys::core::LLVMMetadataTypeInContext;
+
+        let metadata_type = unsafe {
+            LLVMMetadataTypeInContext(*self.context)
+        };
+
+        MetadataType::new(metadata_type)
+    }
+
+    /// Gets the `TokenType`, which is the type of values such as the funclet pads used
+    /// in funclet based exception handling. It will be assigned the current context.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let token_type = context.token_type();
+    ///
+    /// assert_eq!(*token_type.get_context(), context);
+    /// ```
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8", feature = "llvm3-9", feature = "llvm4-0", feature = "llvm5-0")))]
+    pub fn token_type(&self) -> TokenType {
+        use llvm_sys::core::LLVMTokenTypeInContext;
+
+        let token_type = unsafe {
+            LLVMTokenTypeInContext(*self.context)
+        };
+
+        TokenType::new(token_type)
+    }
+
     /// Gets the `IntType` representing 1 bit width. It will be assigned the current context.
     ///
     /// # Example
@@ -382,6 +457,8 @@ impl Context {
 
     /// Gets the `FloatType` representing a 80 bit width. It will be assigned the current context.
     ///
+    /// This is the x87 extended precision type, often used for C's `long double`.
+    ///
     /// # Example
     ///
     /// ```no_run
@@ -394,11 +471,11 @@ impl Context {
     /// assert_eq!(*x86_f80_type.get_context(), context);
     /// ```
     pub fn x86_f80_type(&self) -> FloatType {
-        let f128_type = unsafe {
+        let x86_f80_type = unsafe {
             LLVMX86FP80TypeInContext(*self.context)
         };
 
-        FloatType::new(f128_type)
+        FloatType::new(x86_f80_type)
     }
 
     /// Gets the `FloatType` representing a 128 bit width. It will be assigned the current context.
@@ -433,16 +510,16 @@ impl Context {
     ///
     /// let context = Context::create();
     ///
-    /// let f128_type = context.ppc_f128_type();
+    /// let ppc_f128_type = context.ppc_f128_type();
     ///
-    /// assert_eq!(*f128_type.get_context(), context);
+    /// assert_eq!(*ppc_f128_type.get_context(), context);
     /// ```
     pub fn ppc_f128_type(&self) -> FloatType {
-        let f128_type = unsafe {
+        let ppc_f128_type = unsafe {
             LLVMPPCFP128TypeInContext(*self.context)
         };
 
-        FloatType::new(f128_type)
+        FloatType::new(ppc_f128_type)
     }
 
     /// Creates a `StructType` definiton from heterogeneous types.
@@ -522,6 +599,64 @@ impl Context {
         StructValue::new(value)
     }
 
+    /// Creates a constant `ArrayValue` of `i8`s from a string, optionally appending a nul byte.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let string = context.const_string("my_string", true);
+    ///
+    /// assert_eq!(string.get_type(), context.i8_type().array_type(10));
+    /// ```
+    pub fn const_string(&self, string: &str, null_terminated: bool) -> ArrayValue {
+        let value = unsafe {
+            LLVMConstStringInContext(*self.context, string.as_ptr() as *const i8, string.len() as u32, !null_terminated as i32)
+        };
+
+        ArrayValue::new(value)
+    }
+
+    /// Creates an inline asm expression of type `ty`, which can be called through
+    /// `Builder::build_call` like a function pointer. `constraints` describes how the
+    /// call's arguments and return value map onto registers, e.g. "=r,r" for one register
+    /// output and one register input. If `has_side_effects` is true, the asm won't be
+    /// removed even if its result goes unused. If `is_align_stack` is true, the stack is
+    /// aligned before the asm runs.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let i64_type = context.i64_type();
+    /// let fn_type = i64_type.fn_type(&[], false);
+    /// let fn_value = module.add_function("read_timestamp", &fn_type, None);
+    /// let entry = fn_value.append_basic_block("entry");
+    /// let rdtsc = context.create_inline_asm(fn_type, "rdtsc", "=A", true, false);
+    ///
+    /// builder.position_at_end(&entry);
+    ///
+    /// let timestamp = builder.build_call(rdtsc, &[], "timestamp", false).left().unwrap();
+    ///
+    /// builder.build_return(Some(&timestamp));
+    /// ```
+    pub fn create_inline_asm(&self, ty: FunctionType, asm: &str, constraints: &str, has_side_effects: bool, is_align_stack: bool) -> PointerValue {
+        let asm = CString::new(asm).expect("Conversion to CString failed unexpectedly");
+        let constraints = CString::new(constraints).expect("Conversion to CString failed unexpectedly");
+
+        let value = unsafe {
+            LLVMConstInlineAsm(ty.as_type_ref(), asm.as_ptr(), constraints.as_ptr(), has_side_effects as i32, is_align_stack as i32)
+        };
+
+        PointerValue::new(value)
+    }
+
     /// Append a named `BasicBlock` at the end of the referenced `FunctionValue`.
     ///
     /// # Example
@@ -705,6 +840,60 @@ impl Context {
         }
     }
 
+    /// Creates an enum `Attribute` in this `Context`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let enum_attribute = context.create_enum_attribute(0, 10);
+    ///
+    /// assert!(enum_attribute.is_enum());
+    /// ```
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+    pub fn create_enum_attribute(&self, kind_id: u32, val: u64) -> Attribute {
+        use llvm_sys::core::LLVMCreateEnumAttribute;
+
+        let attribute = unsafe {
+            LLVMCreateEnumAttribute(*self.context, kind_id, val)
+        };
+
+        Attribute::new(attribute)
+    }
+
+    /// Creates a string `Attribute` in this `Context`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let string_attribute = context.create_string_attribute("my_key_123", "my_val");
+    ///
+    /// assert!(string_attribute.is_string());
+    /// ```
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+    pub fn create_string_attribute(&self, key: &str, val: &str) -> Attribute {
+        use llvm_sys::core::LLVMCreateStringAttribute;
+
+        let attribute = unsafe {
+            LLVMCreateStringAttribute(*self.context, key.as_ptr() as *const _, key.len() as u32, val.as_ptr() as *const _, val.len() as u32)
+        };
+
+        Attribute::new(attribute)
+    }
+
+    // REVIEW: Should this be exposed publicly once we have a safe way to wrap the handler fn?
+    // Passing `None` as the handler restores LLVM's default handling of diagnostics
+    pub(crate) fn set_diagnostic_handler(&self, handler: LLVMDiagnosticHandler, void_ptr: *mut c_void) {
+        unsafe {
+            LLVMContextSetDiagnosticHandler(*self.context, handler, void_ptr)
+        }
+    }
+
     // LLVM 3.9+
     // pub fn get_diagnostic_handler(&self) -> DiagnosticHandler {
     //     let handler = unsafe {
@@ -736,19 +925,38 @@ impl Drop for Context {
 // not have access to the original Rc. I suppose Context could be Option<Rc<LLVMContextRef>>
 // where None is global context
 /// A `ContextRef` is a smart pointer allowing borrowed access to a a type's `Context`.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug)]
 pub struct ContextRef {
     context: Option<Context>,
+    // If true, context shares the Rc of an owning Context and can be dropped normally.
+    // Otherwise it was created from a raw LLVMContextRef and must never dispose of it.
+    shares_rc: bool,
 }
 
 impl ContextRef {
     pub(crate) fn new(context: Context) -> Self {
         ContextRef {
             context: Some(context),
+            shares_rc: false,
         }
     }
+
+    pub(crate) fn from_shared(context: &Context) -> Self {
+        ContextRef {
+            context: Some(Context::new(context.context.clone())),
+            shares_rc: true,
+        }
+    }
+}
+
+impl PartialEq for ContextRef {
+    fn eq(&self, other: &ContextRef) -> bool {
+        self.context == other.context
+    }
 }
 
+impl Eq for ContextRef {}
+
 impl Deref for ContextRef {
     type Target = Context;
 
@@ -759,7 +967,13 @@ impl Deref for ContextRef {
 
 impl Drop for ContextRef {
     fn drop(&mut self) {
-        // REVIEW: If you forget an Rc type like Context, does that mean it never gets decremented?
-        forget(self.context.take());
+        let context = self.context.take();
+
+        // A shared Rc just gets decremented here, and only disposes of the context if every
+        // other owner is already gone. A Context created from a raw pointer must be forgotten
+        // instead, since its Rc is unique and would otherwise dispose of a context it doesn't own
+        if !self.shares_rc {
+            forget(context);
+        }
     }
 }
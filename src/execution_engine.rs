@@ -1,5 +1,5 @@
 use libc::c_int;
-use llvm_sys::execution_engine::{LLVMGetExecutionEngineTargetData, LLVMExecutionEngineRef, LLVMRunFunction, LLVMRunFunctionAsMain, LLVMDisposeExecutionEngine, LLVMGetFunctionAddress, LLVMAddModule, LLVMFindFunction, LLVMLinkInMCJIT, LLVMLinkInInterpreter, LLVMRemoveModule, LLVMGenericValueRef, LLVMFreeMachineCodeForFunction, LLVMAddGlobalMapping, LLVMRunStaticConstructors, LLVMRunStaticDestructors};
+use llvm_sys::execution_engine::{LLVMGetExecutionEngineTargetData, LLVMExecutionEngineRef, LLVMRunFunction, LLVMRunFunctionAsMain, LLVMDisposeExecutionEngine, LLVMGetFunctionAddress, LLVMAddModule, LLVMFindFunction, LLVMLinkInMCJIT, LLVMLinkInInterpreter, LLVMRemoveModule, LLVMGenericValueRef, LLVMFreeMachineCodeForFunction, LLVMAddGlobalMapping, LLVMRunStaticConstructors, LLVMRunStaticDestructors, LLVMGetGlobalValueAddress};
 
 use module::Module;
 use support::LLVMString;
@@ -122,7 +122,8 @@ impl ExecutionEngine {
         }
     }
 
-    /// Maps the specified value to an address.
+    /// Maps the specified value to an address. This is typically used to bind a declared `FunctionValue`
+    /// or `GlobalValue` to a host function or variable, so that JIT compiled code may call back into it.
     ///
     /// # Example
     /// ```no_run
@@ -168,7 +169,8 @@ impl ExecutionEngine {
         }
     }
 
-    /// Adds a module to an `ExecutionEngine`.
+    /// Adds a module to an `ExecutionEngine`, which then takes ownership of it. Symbols defined in any added
+    /// module can then be resolved, ie by `get_function`, including calls made across modules.
     ///
     /// The method will be `Ok(())` if the module does not belong to an `ExecutionEngine` already and `Err(())` otherwise.
     ///
@@ -186,19 +188,38 @@ impl ExecutionEngine {
     /// assert!(ee.add_module(&module).is_err());
     /// ```
     pub fn add_module(&self, module: &Module) -> Result<(), ()> {
-        unsafe {
-            LLVMAddModule(*self.execution_engine, module.module.get())
-        }
-
         if module.owned_by_ee.borrow().is_some() {
             return Err(());
         }
 
+        unsafe {
+            LLVMAddModule(*self.execution_engine, module.module.get())
+        }
+
         *module.owned_by_ee.borrow_mut() = Some(self.clone());
 
         Ok(())
     }
 
+    /// Removes a module from an `ExecutionEngine`, handing ownership of it back to the `Module` so that
+    /// it will be disposed of normally on drop.
+    ///
+    /// The method will be `Err` if the module is not owned by this `ExecutionEngine`.
+    ///
+    /// ```rust,no_run
+    /// use inkwell::targets::{InitializationConfig, Target};
+    /// use inkwell::context::Context;
+    /// use inkwell::OptimizationLevel;
+    ///
+    /// Target::initialize_native(&InitializationConfig::default()).unwrap();
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("test");
+    /// let ee = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+    ///
+    /// assert!(ee.remove_module(&module).is_ok());
+    /// assert!(ee.remove_module(&module).is_err());
+    /// ```
     pub fn remove_module(&self, module: &Module) -> Result<(), RemoveModuleError> {
         match *module.owned_by_ee.borrow() {
             Some(ref ee) if *ee.execution_engine != *self.execution_engine => return Err(RemoveModuleError::IncorrectModuleOwner),
@@ -283,6 +304,46 @@ impl ExecutionEngine {
     pub unsafe fn get_function<F>(&self, fn_name: &str) -> Result<Symbol<F>, FunctionLookupError>
     where F: UnsafeFunctionPointer
     {
+        let address = self.get_function_address(fn_name)?;
+
+        assert_eq!(size_of::<F>(), size_of::<usize>(),
+            "The type `F` must have the same size as a function pointer");
+
+        Ok(Symbol {
+            _execution_engine: self.execution_engine.clone(),
+            inner: transmute_copy(&(address as usize)),
+        })
+    }
+
+    /// Attempts to look up the raw address of a JIT compiled function. This is a lower level
+    /// alternative to `get_function`, which may be useful for functions whose signature it doesn't model.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::targets::{InitializationConfig, Target};
+    /// use inkwell::context::Context;
+    /// use inkwell::OptimizationLevel;
+    ///
+    /// Target::initialize_native(&InitializationConfig::default()).unwrap();
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("test");
+    /// let builder = context.create_builder();
+    /// let void_type = context.void_type();
+    /// let fn_type = void_type.fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    /// let basic_block = fn_value.append_basic_block("entry");
+    ///
+    /// builder.position_at_end(&basic_block);
+    /// builder.build_return(None);
+    ///
+    /// let ee = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+    ///
+    /// assert!(ee.get_function_address("my_fn").is_ok());
+    /// assert!(ee.get_function_address("not_my_fn").is_err());
+    /// ```
+    pub fn get_function_address(&self, fn_name: &str) -> Result<u64, FunctionLookupError> {
         if !self.jit_mode {
             return Err(FunctionLookupError::JITNotEnabled);
         }
@@ -294,7 +355,9 @@ impl ExecutionEngine {
 
         let c_string = CString::new(fn_name).expect("Conversion to CString failed unexpectedly");
 
-        let address = LLVMGetFunctionAddress(*self.execution_engine, c_string.as_ptr());
+        let address = unsafe {
+            LLVMGetFunctionAddress(*self.execution_engine, c_string.as_ptr())
+        };
 
         // REVIEW: Can also return 0 if no targets are initialized.
         // One option might be to set a (thread local?) global to true if any at all of the targets have been
@@ -304,13 +367,58 @@ impl ExecutionEngine {
             return Err(FunctionLookupError::FunctionNotFound);
         }
 
-        assert_eq!(size_of::<F>(), size_of::<usize>(),
-            "The type `F` must have the same size as a function pointer");
+        Ok(address)
+    }
 
-        Ok(Symbol {
-            _execution_engine: self.execution_engine.clone(),
-            inner: transmute_copy(&address),
-        })
+    /// Attempts to look up the address of a JIT compiled global, which allows its memory to be
+    /// shared between Rust and JIT compiled code. Returns `None` if this isn't a JIT `ExecutionEngine`
+    /// or if LLVM can't resolve the global.
+    ///
+    /// # Safety
+    ///
+    /// It is the caller's responsibility to ensure `name` is defined by one of the modules
+    /// added to this `ExecutionEngine`, as looking up any other name may segfault on LLVM 5.0 & 6.0.
+    /// Unlike `get_function_address`, this can't be checked up front since LLVM has no equivalent
+    /// of `LLVMFindFunction` for globals.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::targets::{InitializationConfig, Target};
+    /// use inkwell::context::Context;
+    /// use inkwell::OptimizationLevel;
+    ///
+    /// Target::initialize_native(&InitializationConfig::default()).unwrap();
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("test");
+    /// let i32_type = context.i32_type();
+    /// let global = module.add_global(i32_type, None, "my_global");
+    ///
+    /// global.set_initializer(&i32_type.const_int(7, false));
+    ///
+    /// let ee = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+    ///
+    /// unsafe {
+    ///     let address = ee.get_global_value_address("my_global").unwrap();
+    ///
+    ///     assert_eq!(*(address as *const i32), 7);
+    /// }
+    /// ```
+    pub unsafe fn get_global_value_address(&self, name: &str) -> Option<u64> {
+        if !self.jit_mode {
+            return None;
+        }
+
+        let c_string = CString::new(name).expect("Conversion to CString failed unexpectedly");
+
+        let address = LLVMGetGlobalValueAddress(*self.execution_engine, c_string.as_ptr());
+
+        if address == 0 {
+            return None;
+        }
+
+        Some(address)
     }
 
     // REVIEW: Not sure if an EE's target data can change.. if so we might want to update the value
@@ -341,6 +449,44 @@ impl ExecutionEngine {
         Err(FunctionLookupError::FunctionNotFound)
     }
 
+    /// Runs a function in this `ExecutionEngine` with `GenericValue` arguments, returning its
+    /// result as a `GenericValue`. This is the only way to run functions in an interpreter
+    /// `ExecutionEngine`, though JIT `ExecutionEngine`s only support a few signatures here, such
+    /// as that of `main`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::execution_engine::ExecutionEngine;
+    /// use inkwell::targets::{InitializationConfig, Target};
+    ///
+    /// Target::initialize_native(&InitializationConfig::default()).unwrap();
+    /// ExecutionEngine::link_in_interpreter();
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    /// let fn_value = module.add_function("double", &fn_type, None);
+    /// let entry = fn_value.append_basic_block("entry");
+    ///
+    /// builder.position_at_end(&entry);
+    ///
+    /// let x = fn_value.get_first_param().unwrap().into_int_value();
+    /// let doubled = builder.build_int_add(x, x, "doubled");
+    ///
+    /// builder.build_return(Some(&doubled));
+    ///
+    /// let ee = module.create_interpreter_execution_engine().unwrap();
+    /// let arg = i32_type.create_generic_value(21, false);
+    /// let result = unsafe {
+    ///     ee.run_function(&fn_value, &[&arg])
+    /// };
+    ///
+    /// assert_eq!(result.as_int(false), 42);
+    /// ```
     // TODOC: Marked as unsafe because input function could very well do something unsafe. It's up to the caller
     // to ensure that doesn't happen by defining their function correctly.
     pub unsafe fn run_function(&self, function: &FunctionValue, args: &[&GenericValue]) -> GenericValue {
@@ -371,12 +517,17 @@ impl ExecutionEngine {
         }
     }
 
+    /// Runs the static constructors listed in `llvm.global_ctors` of each `Module` owned by
+    /// this `ExecutionEngine`. For JIT `ExecutionEngine`s, this should only be called once its
+    /// code has been finalized, ie after looking up a function with `get_function`.
     pub fn run_static_constructors(&self) {
         unsafe {
             LLVMRunStaticConstructors(*self.execution_engine)
         }
     }
 
+    /// Runs the static destructors listed in `llvm.global_dtors` of each `Module` owned by
+    /// this `ExecutionEngine`. The same finalization requirement as `run_static_constructors` applies.
     pub fn run_static_destructors(&self) {
         unsafe {
             LLVMRunStaticDestructors(*self.execution_engine)
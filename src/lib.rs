@@ -11,6 +11,9 @@ extern crate enum_methods;
 extern crate libc;
 extern crate llvm_sys;
 
+#[deny(missing_docs)]
+#[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+pub mod attributes;
 #[deny(missing_docs)]
 pub mod basic_block;
 pub mod builder;
@@ -27,7 +30,7 @@ pub mod targets;
 pub mod types;
 pub mod values;
 
-use llvm_sys::{LLVMIntPredicate, LLVMRealPredicate, LLVMVisibility, LLVMThreadLocalMode, LLVMDLLStorageClass};
+use llvm_sys::{LLVMIntPredicate, LLVMRealPredicate, LLVMVisibility, LLVMThreadLocalMode, LLVMDLLStorageClass, LLVMAtomicOrdering, LLVMAtomicRMWBinOp};
 
 #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8", feature = "llvm3-9", feature = "llvm4-0",
               feature = "llvm5-0", feature = "llvm6-0")))]
@@ -47,16 +50,31 @@ pub fn enable_llvm_pretty_stack_trace() {
 
 /// Defines the address space in which a global will be inserted.
 ///
+/// The named variants follow the NVPTX numbering. Targets which assign other
+/// meanings or numbers to their address spaces can use `AddressSpace::Custom`
+/// or convert from a `u32` directly.
+///
 /// # Remarks
 /// See also: https://llvm.org/doxygen/NVPTXBaseInfo_8h_source.html
-#[repr(u32)]
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+///
+/// # Example
+///
+/// ```no_run
+/// use inkwell::AddressSpace;
+///
+/// assert_eq!(AddressSpace::from(1), AddressSpace::Global);
+/// assert_eq!(AddressSpace::from(7), AddressSpace::Custom(7));
+/// assert_eq!(u32::from(AddressSpace::Local), 5);
+/// ```
+#[derive(Debug, Eq, Copy, Clone)]
 pub enum AddressSpace {
-    Generic = 0,
-    Global  = 1,
-    Shared  = 3,
-    Const   = 4,
-    Local   = 5
+    Generic,
+    Global,
+    Shared,
+    Const,
+    Local,
+    /// Any address space not covered by the named variants.
+    Custom(u32),
 }
 
 impl From<u32> for AddressSpace {
@@ -64,14 +82,34 @@ impl From<u32> for AddressSpace {
         match val {
             0 => AddressSpace::Generic,
             1 => AddressSpace::Global,
-            2 => AddressSpace::Shared,
-            3 => AddressSpace::Const,
-            4 => AddressSpace::Local,
-            _ => unreachable!("Invalid value for AddressSpace"),
+            3 => AddressSpace::Shared,
+            4 => AddressSpace::Const,
+            5 => AddressSpace::Local,
+            _ => AddressSpace::Custom(val),
+        }
+    }
+}
+
+impl From<AddressSpace> for u32 {
+    fn from(address_space: AddressSpace) -> Self {
+        match address_space {
+            AddressSpace::Generic => 0,
+            AddressSpace::Global => 1,
+            AddressSpace::Shared => 3,
+            AddressSpace::Const => 4,
+            AddressSpace::Local => 5,
+            AddressSpace::Custom(val) => val,
         }
     }
 }
 
+// Compares by number so that `AddressSpace::Custom(1)` equals `AddressSpace::Global`
+impl PartialEq for AddressSpace {
+    fn eq(&self, other: &AddressSpace) -> bool {
+        u32::from(*self) == u32::from(*other)
+    }
+}
+
 // REVIEW: Maybe this belongs in some sort of prelude?
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum IntPredicate {
@@ -165,6 +203,99 @@ impl FloatPredicate {
     }
 }
 
+/// Defines the ordering constraints of an atomic memory operation.
+///
+/// # Remarks
+/// See also: https://llvm.org/docs/LangRef.html#ordering
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AtomicOrdering {
+    /// The weakest ordering. Only guarantees that no value is torn, ie `unordered`.
+    Unordered,
+    /// There is a single total order of modifications for each address, ie `monotonic`.
+    Monotonic,
+    /// Subsequent operations may not be moved before this one, ie `acquire`.
+    Acquire,
+    /// Prior operations may not be moved after this one, ie `release`.
+    Release,
+    /// Acts as both an `Acquire` and a `Release`, ie `acq_rel`.
+    AcquireRelease,
+    /// Additionally partakes in a global order of all sequentially consistent operations, ie `seq_cst`.
+    SequentiallyConsistent,
+}
+
+impl AtomicOrdering {
+    // Follows LLVM's lattice, in which acquire and release are incomparable
+    pub(crate) fn is_stronger_than(&self, other: AtomicOrdering) -> bool {
+        fn rank(ordering: AtomicOrdering) -> u8 {
+            match ordering {
+                AtomicOrdering::Unordered => 0,
+                AtomicOrdering::Monotonic => 1,
+                AtomicOrdering::Acquire | AtomicOrdering::Release => 2,
+                AtomicOrdering::AcquireRelease => 3,
+                AtomicOrdering::SequentiallyConsistent => 4,
+            }
+        }
+
+        rank(*self) > rank(other)
+    }
+
+    pub(crate) fn as_llvm_ordering(&self) -> LLVMAtomicOrdering {
+        match *self {
+            AtomicOrdering::Unordered => LLVMAtomicOrdering::LLVMAtomicOrderingUnordered,
+            AtomicOrdering::Monotonic => LLVMAtomicOrdering::LLVMAtomicOrderingMonotonic,
+            AtomicOrdering::Acquire => LLVMAtomicOrdering::LLVMAtomicOrderingAcquire,
+            AtomicOrdering::Release => LLVMAtomicOrdering::LLVMAtomicOrderingRelease,
+            AtomicOrdering::AcquireRelease => LLVMAtomicOrdering::LLVMAtomicOrderingAcquireRelease,
+            AtomicOrdering::SequentiallyConsistent => LLVMAtomicOrdering::LLVMAtomicOrderingSequentiallyConsistent,
+        }
+    }
+}
+
+/// Defines the operation an `atomicrmw` instruction applies to the value in memory.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AtomicRMWBinOp {
+    /// Stores the new value, ie `xchg`.
+    Xchg,
+    /// Adds the new value to the old one.
+    Add,
+    /// Subtracts the new value from the old one.
+    Sub,
+    /// Bitwise ands the new value with the old one.
+    And,
+    /// Bitwise nands the new value with the old one.
+    Nand,
+    /// Bitwise ors the new value with the old one.
+    Or,
+    /// Bitwise xors the new value with the old one.
+    Xor,
+    /// Stores the greater of the two values, compared as signed integers.
+    Max,
+    /// Stores the lesser of the two values, compared as signed integers.
+    Min,
+    /// Stores the greater of the two values, compared as unsigned integers.
+    UMax,
+    /// Stores the lesser of the two values, compared as unsigned integers.
+    UMin,
+}
+
+impl AtomicRMWBinOp {
+    pub(crate) fn as_llvm_bin_op(&self) -> LLVMAtomicRMWBinOp {
+        match *self {
+            AtomicRMWBinOp::Xchg => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpXchg,
+            AtomicRMWBinOp::Add => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpAdd,
+            AtomicRMWBinOp::Sub => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpSub,
+            AtomicRMWBinOp::And => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpAnd,
+            AtomicRMWBinOp::Nand => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpNand,
+            AtomicRMWBinOp::Or => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpOr,
+            AtomicRMWBinOp::Xor => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpXor,
+            AtomicRMWBinOp::Max => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpMax,
+            AtomicRMWBinOp::Min => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpMin,
+            AtomicRMWBinOp::UMax => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpUMax,
+            AtomicRMWBinOp::UMin => LLVMAtomicRMWBinOp::LLVMAtomicRMWBinOpUMin,
+        }
+    }
+}
+
 
 /// Defines the optimization level used to compile a `Module`.
 ///
@@ -187,10 +318,14 @@ impl Default for OptimizationLevel {
 }
 
 // REVIEW: Maybe this belongs in some sort of prelude?
+/// Defines the visibility of a global or function's symbol outside of its shared object.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum GlobalVisibility {
+    /// Visible to other modules, and may be overridden by them.
     Default,
+    /// Not visible outside of the shared object it is defined in.
     Hidden,
+    /// Visible to other modules, but may not be overridden by them.
     Protected,
 }
 
@@ -219,11 +354,16 @@ impl GlobalVisibility {
     }
 }
 
+/// Defines the thread local storage model of a thread local global.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum ThreadLocalMode {
+    /// The most general model, which works in all cases.
     GeneralDynamicTLSModel,
+    /// For variables only used within the current shared library.
     LocalDynamicTLSModel,
+    /// For variables in modules that will not be loaded dynamically.
     InitialExecTLSModel,
+    /// For variables defined in the executable and only used within it.
     LocalExecTLSModel,
 }
 
@@ -249,10 +389,14 @@ impl ThreadLocalMode {
     }
 }
 
+/// Defines how a global or function is imported from or exported to a Windows DLL.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum DLLStorageClass {
+    /// Neither imported nor exported.
     Default,
+    /// Imported from a DLL, ie `dllimport`.
     Import,
+    /// Exported from a DLL, ie `dllexport`.
     Export,
 }
 
@@ -281,6 +425,24 @@ impl DLLStorageClass {
     }
 }
 
+/// Defines whether or not the address of a global is significant.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UnnamedAddress {
+    /// The address of the global is significant.
+    None,
+    /// The address of the global is insignificant within its module, ie `local_unnamed_addr`.
+    Local,
+    /// The address of the global is insignificant, ie `unnamed_addr`. Such constants may be merged.
+    Global,
+}
+
+impl Default for UnnamedAddress {
+    /// Returns the default value for `UnnamedAddress`, namely `UnnamedAddress::None`.
+    fn default() -> Self {
+        UnnamedAddress::None
+    }
+}
+
 // Misc Notes
 
 // Initializer (new) strategy:
@@ -6,7 +6,7 @@ use object_file::ObjectFile;
 use support::LLVMString;
 
 use std::ffi::{CString, CStr};
-use std::mem::{forget, zeroed};
+use std::mem::zeroed;
 use std::path::Path;
 use std::ptr;
 use std::slice;
@@ -43,6 +43,21 @@ impl MemoryBuffer {
         Ok(MemoryBuffer::new(memory_buffer))
     }
 
+    /// Reads the entirety of standard input into a new `MemoryBuffer`, returning
+    /// LLVM's diagnostic message if it could not be read.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::memory_buffer::MemoryBuffer;
+    /// use inkwell::module::Module;
+    ///
+    /// // ie `my_tool < input.bc`
+    /// let context = Context::create();
+    /// let memory_buffer = MemoryBuffer::create_from_stdin().unwrap();
+    /// let module = Module::parse_bitcode_from_buffer_in_context(&memory_buffer, &context).unwrap();
+    /// ```
     pub fn create_from_stdin() -> Result<Self, LLVMString> {
         let mut memory_buffer = ptr::null_mut();
         let mut err_string = unsafe { zeroed() };
@@ -59,45 +74,85 @@ impl MemoryBuffer {
         Ok(MemoryBuffer::new(memory_buffer))
     }
 
-    // REVIEW: Does this make more sense to take a byte array as input?
-    /// This will create a new `MemoryBuffer` from the given input.
+    /// This will create a new `MemoryBuffer` which points directly at the given input
+    /// rather than copying it.
+    ///
+    /// When the input can't be kept alive, such as for data downloaded into a `Vec<u8>` which
+    /// is dropped early, use `create_from_memory_range_copy` instead.
+    ///
+    /// # Safety
     ///
-    /// This function is likely slightly cheaper than `create_from_memory_range_copy` since it intentionally
-    /// leaks data to LLVM so that it doesn't have to reallocate. `create_from_memory_range_copy` may be removed
-    /// in the future
-    pub fn create_from_memory_range(input: &str, name: &str) -> Self {
-        let input_c_string = CString::new(input).expect("Conversion to CString failed unexpectedly");
+    /// LLVM does not take ownership of `input`, so it is the caller's responsibility to ensure
+    /// it outlives the returned `MemoryBuffer` and anything parsed from it. Additionally, if the
+    /// buffer is handed to LLVM's textual IR parser, the byte after `input` must be nul, as the
+    /// parser reads up to one past its end.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::memory_buffer::MemoryBuffer;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let bitcode = module.write_bitcode_to_memory();
+    /// let memory_buffer = unsafe {
+    ///     MemoryBuffer::create_from_memory_range(bitcode.as_slice(), "my_bitcode")
+    /// };
+    ///
+    /// assert_eq!(memory_buffer.as_slice(), bitcode.as_slice());
+    /// ```
+    // REVIEW: This could be made safe by borrowing input via a lifetime on MemoryBuffer
+    pub unsafe fn create_from_memory_range(input: &[u8], name: &str) -> Self {
         let name_c_string = CString::new(name).expect("Conversion to CString failed unexpectedly");
 
-        let memory_buffer = unsafe {
-            LLVMCreateMemoryBufferWithMemoryRange(input_c_string.as_ptr(), input.len(), name_c_string.as_ptr(), false as i32)
-        };
-
-        // LLVM seems to want to take ownership of input_c_string, which is why we need to forget it
-        // This originally was discovered when not forgetting it caused a subsequent as_slice call
-        // to sometimes return partially garbage data
-        // REVIEW: Does this apply to name_c_string as well?
-        forget(input_c_string);
+        let memory_buffer = LLVMCreateMemoryBufferWithMemoryRange(input.as_ptr() as *const i8, input.len(), name_c_string.as_ptr(), false as i32);
 
         MemoryBuffer::new(memory_buffer)
     }
 
-    /// This will create a new `MemoryBuffer` from the given input.
+    /// This will create a new `MemoryBuffer` which owns a copy of the given input. This is
+    /// slightly more expensive than `create_from_memory_range`, but the input may be dropped
+    /// as soon as this returns.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::memory_buffer::MemoryBuffer;
+    /// use inkwell::module::Module;
+    ///
+    /// let context = Context::create();
+    /// let bitcode: Vec<u8> = Vec::new(); // Downloaded from somewhere
+    /// let memory_buffer = MemoryBuffer::create_from_memory_range_copy(&bitcode, "my_bitcode");
     ///
-    /// This function is likely slightly more expensive than `create_from_memory_range` since it does not leak
-    /// data to LLVM, forcing LLVM to make a copy. This function may be removed in the future in favor of
-    /// `create_from_memory_range`
-    pub fn create_from_memory_range_copy(input: &str, name: &str) -> Self {
-        let input_c_string = CString::new(input).expect("Conversion to CString failed unexpectedly");
+    /// drop(bitcode);
+    ///
+    /// let module = Module::parse_bitcode_from_buffer_in_context(&memory_buffer, &context);
+    /// ```
+    pub fn create_from_memory_range_copy(input: &[u8], name: &str) -> Self {
         let name_c_string = CString::new(name).expect("Conversion to CString failed unexpectedly");
 
         let memory_buffer = unsafe {
-            LLVMCreateMemoryBufferWithMemoryRangeCopy(input_c_string.as_ptr(), input.len(), name_c_string.as_ptr())
+            LLVMCreateMemoryBufferWithMemoryRangeCopy(input.as_ptr() as *const i8, input.len(), name_c_string.as_ptr())
         };
 
         MemoryBuffer::new(memory_buffer)
     }
 
+    /// Gets a byte slice of this `MemoryBuffer`'s contents, which cannot outlive the buffer.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let memory_buffer = module.write_bitcode_to_memory();
+    ///
+    /// assert!(memory_buffer.as_slice().starts_with(b"BC\xC0\xDE"));
+    /// ```
     pub fn as_slice(&self) -> &[u8] {
         unsafe {
             let start = LLVMGetBufferStart(self.memory_buffer);
@@ -106,6 +161,7 @@ impl MemoryBuffer {
         }
     }
 
+    /// Gets the size of this `MemoryBuffer`'s contents in bytes.
     pub fn get_size(&self) -> usize {
         unsafe {
             LLVMGetBufferSize(self.memory_buffer)
@@ -3,18 +3,19 @@
 use llvm_sys::analysis::{LLVMVerifyModule, LLVMVerifierFailureAction};
 use llvm_sys::bit_reader::{LLVMParseBitcode, LLVMParseBitcodeInContext};
 use llvm_sys::bit_writer::{LLVMWriteBitcodeToFile, LLVMWriteBitcodeToMemoryBuffer};
-use llvm_sys::core::{LLVMAddFunction, LLVMAddGlobal, LLVMDumpModule, LLVMGetNamedFunction, LLVMGetTypeByName, LLVMSetDataLayout, LLVMSetTarget, LLVMCloneModule, LLVMDisposeModule, LLVMGetTarget, LLVMModuleCreateWithName, LLVMGetModuleContext, LLVMGetFirstFunction, LLVMGetLastFunction, LLVMSetLinkage, LLVMAddGlobalInAddressSpace, LLVMPrintModuleToString, LLVMGetNamedMetadataNumOperands, LLVMAddNamedMetadataOperand, LLVMGetNamedMetadataOperands, LLVMGetFirstGlobal, LLVMGetLastGlobal, LLVMGetNamedGlobal, LLVMPrintModuleToFile, LLVMSetModuleInlineAsm};
+use llvm_sys::core::{LLVMAddFunction, LLVMAddGlobal, LLVMDumpModule, LLVMGetNamedFunction, LLVMGetTypeByName, LLVMSetDataLayout, LLVMSetTarget, LLVMCloneModule, LLVMDisposeModule, LLVMGetTarget, LLVMModuleCreateWithName, LLVMGetModuleContext, LLVMGetFirstFunction, LLVMGetLastFunction, LLVMSetLinkage, LLVMAddGlobalInAddressSpace, LLVMPrintModuleToString, LLVMGetNamedMetadataNumOperands, LLVMAddNamedMetadataOperand, LLVMGetNamedMetadataOperands, LLVMGetFirstGlobal, LLVMGetLastGlobal, LLVMGetNamedGlobal, LLVMPrintModuleToFile, LLVMSetModuleInlineAsm, LLVMMDNodeInContext, LLVMAddAlias};
 use llvm_sys::execution_engine::{LLVMCreateInterpreterForModule, LLVMCreateJITCompilerForModule, LLVMCreateExecutionEngineForModule};
 use llvm_sys::prelude::{LLVMValueRef, LLVMModuleRef};
 use llvm_sys::LLVMLinkage;
 
 use std::cell::{Cell, RefCell, Ref};
 use std::ffi::{CString, CStr};
+use std::fmt;
 use std::fs::File;
 use std::mem::{forget, uninitialized, zeroed};
 use std::path::Path;
+use std::ptr;
 use std::rc::Rc;
-use std::slice::from_raw_parts;
 
 use {AddressSpace, OptimizationLevel};
 use context::{Context, ContextRef};
@@ -23,8 +24,8 @@ use execution_engine::ExecutionEngine;
 use memory_buffer::MemoryBuffer;
 use support::LLVMString;
 use targets::Target;
-use types::{AsTypeRef, BasicType, FunctionType, BasicTypeEnum};
-use values::{AsValueRef, FunctionValue, GlobalValue, MetadataValue};
+use types::{AsTypeRef, BasicType, FunctionType, BasicTypeEnum, PointerType};
+use values::{AsValueRef, BasicValue, FunctionValue, GlobalValue, MetadataValue};
 
 /// This enum defines how to link a global variable or function in a module. The variant documenation is
 /// mostly taken straight from LLVM's own documentation except for some minor clarification.
@@ -159,9 +160,74 @@ impl Linkage {
     }
 }
 
+/// Defines the behavior of a module flag when two modules which both specify it are linked together.
+/// The values match those of LLVM's `Module::ModFlagBehavior`.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[repr(u32)]
+pub enum FlagBehavior {
+    /// Emits an error if two values disagree, otherwise the resulting value is that of the operands.
+    Error = 1,
+    /// Emits a warning if two values disagree. The result value will be the operand for the flag from the first module being linked.
+    Warning = 2,
+    /// Adds a requirement that another module flag be present and have a specified value after linking is performed.
+    /// The value must be a metadata pair, where the first element of the pair is the ID of the module flag to be restricted,
+    /// and the second element of the pair is the value the module flag should be restricted to. This behavior can be used
+    /// to restrict the allowable results (via triggering of an error) of linking IDs with the `Override` behavior.
+    Require = 3,
+    /// Uses the specified value, regardless of the behavior or value of the other module. If both modules specify `Override`,
+    /// but the values differ, an error will be emitted.
+    Override = 4,
+    /// Appends the two values, which are required to be metadata nodes.
+    Append = 5,
+    /// Appends the two values, which are required to be metadata nodes. However, duplicate entries in the second list are dropped
+    /// during the append operation.
+    AppendUnique = 6,
+}
+
+/// Defines what LLVM should do when verification fails.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum VerifierFailureAction {
+    /// Prints the problems found to stderr and then terminates the process.
+    AbortProcess,
+    /// Prints the problems found to stderr and returns an error.
+    PrintMessage,
+    /// Only returns an error.
+    ReturnStatus,
+}
+
+impl Default for VerifierFailureAction {
+    /// Returns the default value for `VerifierFailureAction`, namely `VerifierFailureAction::ReturnStatus`.
+    fn default() -> Self {
+        VerifierFailureAction::ReturnStatus
+    }
+}
+
+impl VerifierFailureAction {
+    pub(crate) fn as_llvm_action(&self) -> LLVMVerifierFailureAction {
+        match *self {
+            VerifierFailureAction::AbortProcess => LLVMVerifierFailureAction::LLVMAbortProcessAction,
+            VerifierFailureAction::PrintMessage => LLVMVerifierFailureAction::LLVMPrintMessageAction,
+            VerifierFailureAction::ReturnStatus => LLVMVerifierFailureAction::LLVMReturnStatusAction,
+        }
+    }
+}
+
 /// Represents a reference to an LLVM `Module`.
 /// The underlying module will be disposed when dropping this object.
-#[derive(Debug, PartialEq, Eq)]
+///
+/// A `Module` can be formatted with `{}` to obtain its textual IR.
+///
+/// # Example
+///
+/// ```no_run
+/// use inkwell::context::Context;
+///
+/// let context = Context::create();
+/// let module = context.create_module("my_module");
+///
+/// assert!(format!("{}", module).contains("ModuleID = 'my_module'"));
+/// ```
+#[derive(PartialEq, Eq)]
 pub struct Module {
     pub(crate) non_global_context: Option<Context>, // REVIEW: Could we just set context to the global context?
     data_layout: RefCell<Option<DataLayout>>,
@@ -241,6 +307,33 @@ impl Module {
         fn_value
     }
 
+    /// Gets a `FunctionValue` in this `Module` by its `name`, or creates one with the
+    /// given `ty` and `linkage` if no such function exists, like `add_function`.
+    ///
+    /// Note that, like LLVM's `getOrInsertFunction`, an existing function is returned
+    /// even when its type differs from `ty`, and `linkage` is then ignored. Callers
+    /// relying on `ty` should check `get_type` and bitcast the function if needed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let fn_value = module.get_or_add_function("my_fn", &fn_type, None);
+    ///
+    /// assert_eq!(module.get_or_add_function("my_fn", &fn_type, None), fn_value);
+    /// assert_eq!(module.get_function_count(), 1);
+    /// ```
+    pub fn get_or_add_function(&self, name: &str, ty: &FunctionType, linkage: Option<Linkage>) -> FunctionValue {
+        match self.get_function(name) {
+            Some(fn_value) => fn_value,
+            None => self.add_function(name, ty, linkage),
+        }
+    }
+
     /// Gets the `Context` from which this `Module` originates.
     ///
     /// # Example
@@ -260,14 +353,68 @@ impl Module {
     /// assert_ne!(local_context, *global_context);
     /// ```
     pub fn get_context(&self) -> ContextRef {
+        if let Some(ref context) = self.non_global_context {
+            return ContextRef::from_shared(context);
+        }
+
         let context = unsafe {
             LLVMGetModuleContext(self.module.get())
         };
 
-        // REVIEW: This probably should be somehow using the existing context Rc
         ContextRef::new(Context::new(Rc::new(context)))
     }
 
+    /// Gets the name of this `Module`, also known as its module identifier.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    ///
+    /// assert_eq!(module.get_name().to_str(), Ok("my_module"));
+    /// ```
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+    pub fn get_name(&self) -> &CStr {
+        use llvm_sys::core::LLVMGetModuleIdentifier;
+
+        let mut length = 0;
+        let chars = unsafe {
+            LLVMGetModuleIdentifier(self.module.get(), &mut length)
+        };
+
+        unsafe {
+            CStr::from_ptr(chars)
+        }
+    }
+
+    /// Assigns the name of this `Module`, also known as its module identifier.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    ///
+    /// module.set_name("my_module2");
+    ///
+    /// assert_eq!(module.get_name().to_str(), Ok("my_module2"));
+    /// ```
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+    pub fn set_name(&self, name: &str) {
+        use llvm_sys::core::LLVMSetModuleIdentifier;
+
+        let c_string = CString::new(name).expect("Conversion to CString failed unexpectedly");
+
+        unsafe {
+            LLVMSetModuleIdentifier(self.module.get(), c_string.as_ptr(), name.len())
+        }
+    }
+
     /// Gets the first `FunctionValue` defined in this `Module`.
     ///
     /// # Example
@@ -348,6 +495,52 @@ impl Module {
         FunctionValue::new(value)
     }
 
+    /// An iterator over all `FunctionValue`s defined in this `Module`, in the order they were added.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    ///
+    /// assert_eq!(module.get_functions().count(), 0);
+    ///
+    /// let void_type = context.void_type();
+    /// let fn_type = void_type.fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    /// let fn_value2 = module.add_function("my_fn2", &fn_type, None);
+    ///
+    /// assert_eq!(module.get_functions().count(), 2);
+    /// assert_eq!(module.get_functions().collect::<Vec<_>>(), vec![fn_value, fn_value2]);
+    /// ```
+    pub fn get_functions(&self) -> FunctionIterator {
+        FunctionIterator(self.get_first_function())
+    }
+
+    /// Counts the number of `FunctionValue`s in this `Module`. LLVM provides no direct way
+    /// of obtaining this, so it walks all functions and is therefore O(n).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let void_type = context.void_type();
+    /// let fn_type = void_type.fn_type(&[], false);
+    ///
+    /// assert_eq!(module.get_function_count(), 0);
+    ///
+    /// module.add_function("my_fn", &fn_type, None);
+    ///
+    /// assert_eq!(module.get_function_count(), 1);
+    /// ```
+    pub fn get_function_count(&self) -> u32 {
+        self.get_functions().count() as u32
+    }
 
     /// Gets a `BasicTypeEnum` of a named type in a `Module`.
     ///
@@ -379,7 +572,9 @@ impl Module {
         Some(BasicTypeEnum::new(type_))
     }
 
-    /// Sets a `Target` to this `Module`.
+    /// Sets a `Target` to this `Module`. Note that this stores the `Target`'s name, e.g. "x86-64",
+    /// in place of the module's triple, rather than a real triple such as "x86_64-unknown-linux-gnu".
+    /// Use `set_triple` to set the latter.
     ///
     /// # Example
     ///
@@ -405,7 +600,8 @@ impl Module {
         }
     }
 
-    /// Gets the `Target` assigned to this `Module`, if any.
+    /// Gets the `Target` assigned to this `Module` by `set_target`, if any. This looks the
+    /// module's triple up as a `Target` name, so it is `None` after `set_triple` sets a real triple.
     ///
     /// # Example
     ///
@@ -426,7 +622,9 @@ impl Module {
     /// assert_eq!(module.get_target().unwrap(), target);
     /// ```
     pub fn get_target(&self) -> Option<Target> {
-        // REVIEW: This isn't an owned LLVMString, is it? If so, need to deallocate.
+        // LLVMGetTarget returns a pointer into the Module's own triple rather than an owned
+        // LLVMString, so it must not be deallocated. It is only valid until the triple is next
+        // set, but the lookup doesn't retain it: the resulting Target is a static LLVM registry entry
         let target_str = unsafe {
             LLVMGetTarget(self.module.get())
         };
@@ -434,8 +632,59 @@ impl Module {
         Target::from_name_raw(target_str)
     }
 
+    /// Sets the target triple of this `Module`, e.g. "x86_64-unknown-linux-gnu", replacing any
+    /// `Target` name set by `set_target`. The triple needn't belong to a `Target` LLVM was built with.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("mod");
+    ///
+    /// module.set_triple("wasm32-unknown-unknown");
+    ///
+    /// assert_eq!(module.get_triple().to_str(), Ok("wasm32-unknown-unknown"));
+    /// ```
+    pub fn set_triple(&self, triple: &str) {
+        let c_string = CString::new(triple).expect("Conversion to CString failed unexpectedly");
+
+        unsafe {
+            LLVMSetTarget(self.module.get(), c_string.as_ptr())
+        }
+    }
+
+    /// Gets the target triple of this `Module`, which is empty if none has been set.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("mod");
+    ///
+    /// assert_eq!(module.get_triple().to_bytes(), b"");
+    ///
+    /// module.set_triple("x86_64-unknown-linux-gnu");
+    ///
+    /// assert_eq!(module.get_triple().to_str(), Ok("x86_64-unknown-linux-gnu"));
+    /// ```
+    pub fn get_triple(&self) -> LLVMString {
+        // See get_target: the module owns this string, so a copy is made for the LLVMString
+        let triple = unsafe {
+            LLVMGetTarget(self.module.get())
+        };
+
+        LLVMString::create(triple)
+    }
+
     /// Creates an `ExecutionEngine` from this `Module`.
     ///
+    /// Note that if the `ExecutionEngine` cannot be created, LLVM disposes of the underlying
+    /// module, so this `Module` and any values obtained from it must not be used afterwards.
+    ///
     /// # Example
     /// ```no_run
     /// use inkwell::context::Context;
@@ -471,6 +720,9 @@ impl Module {
 
     /// Creates an interpreter `ExecutionEngine` from this `Module`.
     ///
+    /// Note that if the `ExecutionEngine` cannot be created, LLVM disposes of the underlying
+    /// module, so this `Module` and any values obtained from it must not be used afterwards.
+    ///
     /// # Example
     /// ```no_run
     /// use inkwell::context::Context;
@@ -507,6 +759,9 @@ impl Module {
 
     /// Creates a JIT `ExecutionEngine` from this `Module`.
     ///
+    /// Note that if the `ExecutionEngine` cannot be created, LLVM disposes of the underlying
+    /// module, so this `Module` and any values obtained from it must not be used afterwards.
+    ///
     /// # Example
     /// ```no_run
     /// use inkwell::OptimizationLevel;
@@ -532,18 +787,6 @@ impl Module {
         };
 
         if code == 1 {
-            // The module still seems "owned" in this error case, despite failing to create an EE. This would normally
-            // end in a segfault on Module drop, however we're avoiding that by cloning the module and replacing the underlying pointer
-            // REVIEW: Ensure this doesn't lead to unexpected behavior... If it does, the alternate strategy would be to change the fn
-            // signature to take ownership of self and return it with good EE: (self, opt_level) -> Result<(Module, EE), LLVMString>
-            let module = self.clone();
-
-            self.module.set(module.module.get());
-
-            forget(module);
-
-            // REVIEW: Module still seems "owned" in the error case and may segfault on module drop. :/
-            // Need to figure out if there's a way to prevent this.
             return Err(LLVMString::new(err_string));
         }
 
@@ -575,7 +818,7 @@ impl Module {
 
         let value = unsafe {
             match address_space {
-                Some(address_space) => LLVMAddGlobalInAddressSpace(self.module.get(), type_.as_type_ref(), c_string.as_ptr(), address_space as u32),
+                Some(address_space) => LLVMAddGlobalInAddressSpace(self.module.get(), type_.as_type_ref(), c_string.as_ptr(), u32::from(address_space)),
                 None => LLVMAddGlobal(self.module.get(), type_.as_type_ref(), c_string.as_ptr()),
             }
         };
@@ -583,6 +826,40 @@ impl Module {
         GlobalValue::new(value)
     }
 
+    /// Creates a `GlobalValue` alias named `name` to the `aliasee`, which may be another global or a constant
+    /// expression thereof. `type_` is the `PointerType` of the alias itself.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::AddressSpace;
+    /// use inkwell::context::Context;
+    /// use inkwell::module::Linkage;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("mod");
+    /// let i8_type = context.i8_type();
+    /// let global = module.add_global(i8_type, None, "my_global");
+    ///
+    /// global.set_initializer(&i8_type.const_int(1, false));
+    ///
+    /// let alias = module.add_global_alias(i8_type.ptr_type(AddressSpace::Generic), &global, "my_alias");
+    ///
+    /// alias.set_linkage(Linkage::WeakAny);
+    ///
+    /// assert_eq!(alias.get_linkage(), Linkage::WeakAny);
+    /// assert!(module.verify().is_ok());
+    /// ```
+    pub fn add_global_alias<V: AsValueRef>(&self, type_: PointerType, aliasee: &V, name: &str) -> GlobalValue {
+        let c_string = CString::new(name).expect("Conversion to CString failed unexpectedly");
+
+        let value = unsafe {
+            LLVMAddAlias(self.module.get(), type_.as_type_ref(), aliasee.as_value_ref(), c_string.as_ptr())
+        };
+
+        GlobalValue::new(value)
+    }
+
     pub fn write_bitcode_to_path(&self, path: &Path) -> bool {
         let path_str = path.to_str().expect("Did not find a valid Unicode path string");
         let c_string = CString::new(path_str).expect("Conversion to CString failed unexpectedly");
@@ -619,18 +896,74 @@ impl Module {
         MemoryBuffer::new(memory_buffer)
     }
 
+    /// Creates a copy of this `Module` in another `Context`. Unlike `clone`, which keeps
+    /// the copy in this `Module`'s `Context`, this allows IR to be moved between `Context`s,
+    /// e.g. to compile it in isolation. LLVM has no direct way of doing so, so this `Module` is
+    /// written to bitcode in memory and then parsed back in the given `Context`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    ///
+    /// module.add_function("my_fn", &fn_type, None);
+    ///
+    /// let other_context = Context::create();
+    /// let other_module = module.clone_into_context(&other_context).unwrap();
+    ///
+    /// assert_eq!(*other_module.get_context(), other_context);
+    /// assert!(other_module.get_function("my_fn").is_some());
+    /// ```
+    pub fn clone_into_context(&self, context: &Context) -> Result<Module, LLVMString> {
+        let buffer = self.write_bitcode_to_memory();
+        let module = Module::parse_bitcode_from_buffer_in_context(&buffer, context)?;
+
+        // The module identifier isn't part of the bitcode, so it needs to be carried over
+        #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+        module.set_name(&self.get_name().to_string_lossy());
+
+        Ok(module)
+    }
+
     /// Ensures that the current `Module` is valid, and returns a `Result`
     /// that describes whether or not it is, returning a LLVM allocated string on error.
     ///
     /// # Remarks
     /// See also: http://llvm.org/doxygen/Analysis_2Analysis_8cpp_source.html
     pub fn verify(&self) -> Result<(), LLVMString> {
-        let mut err_str = unsafe { zeroed() };
+        self.verify_with_action(VerifierFailureAction::ReturnStatus)
+    }
 
-        let action = LLVMVerifierFailureAction::LLVMReturnStatusAction;
+    /// Ensures that the current `Module` is valid, using the given `VerifierFailureAction`
+    /// to determine what LLVM should do if it is not. Note that `VerifierFailureAction::AbortProcess`
+    /// will terminate the whole process rather than return an error.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::module::VerifierFailureAction;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    ///
+    /// // Missing a terminator:
+    /// fn_value.append_basic_block("entry");
+    ///
+    /// // Prints the problem to stderr in addition to returning it
+    /// assert!(module.verify_with_action(VerifierFailureAction::PrintMessage).is_err());
+    /// ```
+    pub fn verify_with_action(&self, action: VerifierFailureAction) -> Result<(), LLVMString> {
+        let mut err_str = unsafe { zeroed() };
 
         let code = unsafe {
-            LLVMVerifyModule(self.module.get(), action, &mut err_str)
+            LLVMVerifyModule(self.module.get(), action.as_llvm_action(), &mut err_str)
         };
 
         if code == 1 && !err_str.is_null() {
@@ -640,6 +973,9 @@ impl Module {
         Ok(())
     }
 
+    // Replaces the underlying module after LLVM has disposed of it, ie when failing to create an EE.
+    // Any values previously obtained from this Module are no longer valid afterwards
+
     fn get_borrowed_data_layout(module: LLVMModuleRef) -> DataLayout {
         #[cfg(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8"))]
         let data_layout = unsafe {
@@ -703,6 +1039,21 @@ impl Module {
         Ok(())
     }
 
+    /// Sets the module level inline assembly of this `Module`, replacing any that was
+    /// previously set. Each line of `asm` is emitted as its own `module asm` statement.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    ///
+    /// module.set_inline_assembly(".globl my_symbol\nmy_symbol:");
+    ///
+    /// assert!(module.print_to_string().to_string().contains("module asm \".globl my_symbol\""));
+    /// ```
     pub fn set_inline_assembly(&self, asm: &str) {
         let c_string = CString::new(asm).expect("Conversion to CString failed unexpectedly");
 
@@ -711,10 +1062,26 @@ impl Module {
         }
     }
 
+    /// Appends a `MetadataValue` to the named metadata indexed by `key`, creating it if
+    /// it doesn't yet exist. Metadata strings are wrapped in a node by LLVM, since named
+    /// metadata may only contain nodes.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let md_string = context.metadata_string("clang version 6.0.0");
+    ///
+    /// module.add_global_metadata("llvm.ident", &md_string);
+    ///
+    /// assert_eq!(module.get_global_metadata_size("llvm.ident"), 1);
+    /// ```
     // REVIEW: Should module take ownership of metadata?
     // REVIEW: Should we return a MetadataValue for the global since it's its own value?
     // it would be the last item in get_global_metadata I believe
-    // TODOC: Appends your metadata to a global MetadataValue<Node> indexed by key
     pub fn add_global_metadata(&self, key: &str, metadata: &MetadataValue) {
         let c_string = CString::new(key).expect("Conversion to CString failed unexpectedly");
 
@@ -722,8 +1089,8 @@ impl Module {
             LLVMAddNamedMetadataOperand(self.module.get(), c_string.as_ptr(), metadata.as_value_ref())
         }
     }
+    /// Gets the number of operands of the named metadata indexed by `key`, or `0` if it doesn't exist.
     // REVIEW: Better name?
-    // TODOC: Gets the size of the metadata node indexed by key
     pub fn get_global_metadata_size(&self, key: &str) -> u32 {
         let c_string = CString::new(key).expect("Conversion to CString failed unexpectedly");
 
@@ -732,7 +1099,8 @@ impl Module {
         }
     }
 
-    // TODOC: Always returns a metadata node indexed by key, which may contain 1 string or multiple values as its get_node_values()
+    /// Gets the operands of the named metadata indexed by `key`. These are always metadata
+    /// nodes, which may each contain a single string or multiple values as their `get_node_values()`.
     // SubTypes: -> Vec<MetadataValue<Node>>
     pub fn get_global_metadata(&self, key: &str) -> Vec<MetadataValue> {
         let c_string = CString::new(key).expect("Conversion to CString failed unexpectedly");
@@ -743,13 +1111,92 @@ impl Module {
 
         forget(raw_vec);
 
-        let slice = unsafe {
+        let raw_vec = unsafe {
             LLVMGetNamedMetadataOperands(self.module.get(), c_string.as_ptr(), ptr);
 
-            from_raw_parts(ptr, count as usize)
+            Vec::from_raw_parts(ptr, count as usize, count as usize)
         };
 
-        slice.iter().map(|val| MetadataValue::new(*val)).collect()
+        raw_vec.iter().map(|val| MetadataValue::new(*val)).collect()
+    }
+
+    /// Adds a module flag with a `MetadataValue` value, which LLVM uses to communicate information about the module
+    /// as a whole to later stages of compilation. Flags are stored in the "llvm.module.flags" named metadata.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::module::FlagBehavior;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let md_string = context.metadata_string("lots of metadata here");
+    ///
+    /// module.add_module_flag(FlagBehavior::Override, "my_flag", md_string);
+    ///
+    /// assert_eq!(module.get_global_metadata_size("llvm.module.flags"), 1);
+    /// assert!(module.verify().is_ok());
+    /// ```
+    // REVIEW: LLVM 7 added LLVMAddModuleFlag, which we could use once supported. Until then, this is
+    // the equivalent named metadata representation of module flags.
+    pub fn add_module_flag(&self, behavior: FlagBehavior, key: &str, value: MetadataValue) {
+        self.add_flag(behavior, key, value.as_value_ref())
+    }
+
+    /// Adds a module flag with a `BasicValue` value, such as a constant integer.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::module::FlagBehavior;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let i32_three = context.i32_type().const_int(3, false);
+    ///
+    /// module.add_basic_value_flag(FlagBehavior::Warning, "Debug Info Version", i32_three);
+    ///
+    /// assert!(module.verify().is_ok());
+    /// ```
+    pub fn add_basic_value_flag<BV: BasicValue>(&self, behavior: FlagBehavior, key: &str, value: BV) {
+        self.add_flag(behavior, key, value.as_value_ref())
+    }
+
+    fn add_flag(&self, behavior: FlagBehavior, key: &str, value: LLVMValueRef) {
+        let context = self.get_context();
+        let behavior = context.i32_type().const_int(behavior as u64, false);
+        let key = context.metadata_string(key);
+        let mut values = [behavior.as_value_ref(), key.as_value_ref(), value];
+
+        let flag = unsafe {
+            LLVMMDNodeInContext(*context.context, values.as_mut_ptr(), values.len() as u32)
+        };
+
+        self.add_global_metadata("llvm.module.flags", &MetadataValue::new(flag));
+    }
+
+    /// Strips all debug info (ie "llvm.dbg.*" metadata and debug intrinsics) from this `Module`, returning whether or not
+    /// anything was removed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    ///
+    /// assert!(!module.strip_debug_info());
+    /// ```
+    #[cfg(feature = "llvm6-0")]
+    pub fn strip_debug_info(&self) -> bool {
+        use llvm_sys::debuginfo::LLVMStripModuleDebugInfo;
+
+        unsafe {
+            LLVMStripModuleDebugInfo(self.module.get()) == 1
+        }
     }
 
     /// Gets the first `GlobalValue` in a module.
@@ -843,6 +1290,50 @@ impl Module {
         Some(GlobalValue::new(value))
     }
 
+    /// An iterator over all `GlobalValue`s in this `Module`, in the order they were added.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::AddressSpace;
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("mod");
+    /// let i8_type = context.i8_type();
+    ///
+    /// assert_eq!(module.get_globals().count(), 0);
+    ///
+    /// let global = module.add_global(i8_type, Some(AddressSpace::Const), "my_global");
+    ///
+    /// assert_eq!(module.get_globals().collect::<Vec<_>>(), vec![global]);
+    /// ```
+    pub fn get_globals(&self) -> GlobalIterator {
+        GlobalIterator(self.get_first_global())
+    }
+
+    /// Counts the number of `GlobalValue`s in this `Module`. LLVM provides no direct way
+    /// of obtaining this, so it walks all globals and is therefore O(n).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("mod");
+    /// let i8_type = context.i8_type();
+    ///
+    /// assert_eq!(module.get_global_count(), 0);
+    ///
+    /// module.add_global(i8_type, None, "my_global");
+    ///
+    /// assert_eq!(module.get_global_count(), 1);
+    /// ```
+    pub fn get_global_count(&self) -> u32 {
+        self.get_globals().count() as u32
+    }
+
     /// Creates a new `Module` from a `MemoryBuffer`.
     ///
     /// # Example
@@ -960,6 +1451,127 @@ impl Module {
 
         Self::parse_bitcode_from_buffer_in_context(&buffer, &context)
     }
+
+    /// Links another `Module` into this one, consuming it. Both `Module`s must share the same `Context`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("mod");
+    /// let module2 = context.create_module("mod2");
+    /// let void_type = context.void_type();
+    /// let fn_type = void_type.fn_type(&[], false);
+    ///
+    /// module2.add_function("f", &fn_type, None);
+    ///
+    /// assert!(module.link_in_module(module2).is_ok());
+    /// assert!(module.get_function("f").is_some());
+    /// ```
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7")))]
+    pub fn link_in_module(&self, mut other: Self) -> Result<(), LLVMString> {
+        use libc::{c_char, c_void};
+        use llvm_sys::linker::LLVMLinkModules2;
+        use support::error_handling::get_error_str_diagnostic_handler;
+
+        assert_eq!(self.get_context(), other.get_context(), "Cannot link in a Module from a different Context");
+
+        if other.owned_by_ee.borrow().is_some() {
+            let string = "Cannot link a module which is already owned by an ExecutionEngine.\0";
+
+            return Err(LLVMString::create(string.as_ptr() as *const c_char));
+        }
+
+        let context = self.get_context();
+        let mut char_ptr: *mut c_char = ptr::null_mut();
+        let char_ptr_ptr = &mut char_ptr as *mut *mut c_char as *mut c_void;
+
+        // LLVMLinkModules2 no longer provides an error string out param, so we temporarily assign a
+        // diagnostic handler to extract the error message (if any) for us
+        context.set_diagnostic_handler(Some(get_error_str_diagnostic_handler), char_ptr_ptr);
+
+        let code = unsafe {
+            LLVMLinkModules2(self.module.get(), other.module.get()) // Takes ownership of other's module
+        };
+
+        context.set_diagnostic_handler(None, ptr::null_mut());
+
+        // LLVM has already destroyed other's module, so it must not be disposed again on drop.
+        // Its remaining owned fields are released first, so that forgetting it leaks nothing
+        drop(other.non_global_context.take());
+        drop(other.data_layout.borrow_mut().take());
+        forget(other);
+
+        if code == 1 {
+            debug_assert!(!char_ptr.is_null());
+
+            return Err(LLVMString::new(char_ptr));
+        }
+
+        Ok(())
+    }
+}
+
+/// Iterates over all `FunctionValue`s in a `Module`.
+#[derive(Debug)]
+pub struct FunctionIterator(Option<FunctionValue>);
+
+impl Iterator for FunctionIterator {
+    type Item = FunctionValue;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.0.take();
+
+        if let Some(ref fn_value) = current {
+            self.0 = fn_value.get_next_function();
+        }
+
+        current
+    }
+}
+
+/// Iterates over all `GlobalValue`s in a `Module`.
+#[derive(Debug)]
+pub struct GlobalIterator(Option<GlobalValue>);
+
+impl Iterator for GlobalIterator {
+    type Item = GlobalValue;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.0.take();
+
+        if let Some(ref global) = current {
+            self.0 = global.get_next_global();
+        }
+
+        current
+    }
+}
+
+impl fmt::Display for Module {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let llvm_string = self.print_to_string();
+
+        write!(f, "{}", llvm_string.to_string_lossy())
+    }
+}
+
+impl fmt::Debug for Module {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let llvm_value = self.print_to_string();
+        let mut debug_struct = f.debug_struct("Module");
+
+        #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+        debug_struct.field("name", &self.get_name());
+
+        debug_struct
+            .field("address", &self.module.get())
+            .field("owned_by_ee", &self.owned_by_ee)
+            .field("llvm_value", &llvm_value)
+            .finish()
+    }
 }
 
 impl Clone for Module {
@@ -13,6 +13,8 @@ use module::Module;
 use targets::TargetData;
 use values::{AsValueRef, FunctionValue};
 
+use std::marker::PhantomData;
+
 // REVIEW: Opt Level might be identical to targets::Option<CodeGenOptLevel>
 #[derive(Debug)]
 pub struct PassManagerBuilder {
@@ -28,6 +30,28 @@ impl PassManagerBuilder {
         }
     }
 
+    /// Creates a new `PassManagerBuilder`, which can populate `PassManager`s with a
+    /// standard pipeline of passes, similar to clang's `-O` flags.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::OptimizationLevel::Aggressive;
+    /// use inkwell::context::Context;
+    /// use inkwell::passes::{PassManager, PassManagerBuilder};
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let pass_manager_builder = PassManagerBuilder::create();
+    ///
+    /// pass_manager_builder.set_optimization_level(Aggressive);
+    /// pass_manager_builder.set_inliner_with_threshold(275);
+    ///
+    /// let pass_manager = PassManager::create();
+    ///
+    /// pass_manager_builder.populate_module_pass_manager(&pass_manager);
+    /// pass_manager.run_on(&module);
+    /// ```
     pub fn create() -> Self {
         let pass_manager_builder = unsafe {
             LLVMPassManagerBuilderCreate()
@@ -36,12 +60,15 @@ impl PassManagerBuilder {
         PassManagerBuilder::new(pass_manager_builder)
     }
 
+    /// Sets the optimization level of the pipeline, as with clang's `-O0` through `-O3`.
     pub fn set_optimization_level(&self, opt_level: OptimizationLevel) {
         unsafe {
             LLVMPassManagerBuilderSetOptLevel(self.pass_manager_builder, opt_level as u32)
         }
     }
 
+    /// Sets how much the pipeline should favor code size, where `1` and `2` correspond
+    /// to clang's `-Os` and `-Oz` respectively and `0` does not favor it at all.
     // REVIEW: Valid input 0-2 according to llvmlite
     pub fn set_size_level(&self, size_level: u32) {
         unsafe {
@@ -67,28 +94,31 @@ impl PassManagerBuilder {
         }
     }
 
+    /// Adds a function inlining pass to the pipeline, which will inline calls whose
+    /// estimated cost is below the given threshold. For reference, clang uses `225`
+    /// at `-O2` and `275` at `-O3`.
     pub fn set_inliner_with_threshold(&self, threshold: u32) {
         unsafe {
             LLVMPassManagerBuilderUseInlinerWithThreshold(self.pass_manager_builder, threshold)
         }
     }
 
-    // SubType: pass_manager: &PassManager<FunctionValue>
-    pub fn populate_function_pass_manager(&self, pass_manager: &PassManager) {
+    /// Adds the function level passes of the configured pipeline to a `PassManager<FunctionValue>`.
+    pub fn populate_function_pass_manager(&self, pass_manager: &PassManager<FunctionValue>) {
         unsafe {
             LLVMPassManagerBuilderPopulateFunctionPassManager(self.pass_manager_builder, pass_manager.pass_manager)
         }
     }
 
-    // SubType: pass_manager: &PassManager<Module>
-    pub fn populate_module_pass_manager(&self, pass_manager: &PassManager) {
+    /// Adds the passes of the configured pipeline to a `PassManager<Module>`.
+    pub fn populate_module_pass_manager(&self, pass_manager: &PassManager<Module>) {
         unsafe {
             LLVMPassManagerBuilderPopulateModulePassManager(self.pass_manager_builder, pass_manager.pass_manager)
         }
     }
 
     // SubType: Need LTO subtype?
-    pub fn populate_lto_pass_manager(&self, pass_manager: &PassManager, internalize: bool, run_inliner: bool) {
+    pub fn populate_lto_pass_manager(&self, pass_manager: &PassManager<Module>, internalize: bool, run_inliner: bool) {
         unsafe {
             LLVMPassManagerBuilderPopulateLTOPassManager(self.pass_manager_builder, pass_manager.pass_manager, internalize as i32, run_inliner as i32)
         }
@@ -103,23 +133,35 @@ impl Drop for PassManagerBuilder {
     }
 }
 
-// SubTypes: PassManager<Module>, PassManager<FunctionValue>
+/// A `PassManager` runs a pipeline of optimization and analysis passes. A
+/// `PassManager<Module>` runs its passes over entire `Module`s, whereas a
+/// `PassManager<FunctionValue>` runs them over one function at a time.
 #[derive(Debug)]
-pub struct PassManager {
+pub struct PassManager<T> {
     pub(crate) pass_manager: LLVMPassManagerRef,
+    sub_type: PhantomData<T>,
 }
 
-impl PassManager {
-    pub(crate) fn new(pass_manager: LLVMPassManagerRef) -> PassManager {
-        assert!(!pass_manager.is_null());
-
-        PassManager {
-            pass_manager,
-        }
-    }
-
-    // SubTypes: PassManager<Module>::create()
-    pub fn create_for_module() -> Self {
+impl PassManager<Module> {
+    /// Creates a new `PassManager` which runs its passes over whole `Module`s.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::passes::PassManager;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let pass_manager = PassManager::create();
+    ///
+    /// pass_manager.add_instruction_combining_pass();
+    /// pass_manager.add_reassociate_pass();
+    /// pass_manager.add_gvn_pass();
+    /// pass_manager.add_cfg_simplification_pass();
+    /// pass_manager.run_on(&module);
+    /// ```
+    pub fn create() -> Self {
         let pass_manager = unsafe {
             LLVMCreatePassManager()
         };
@@ -127,8 +169,44 @@ impl PassManager {
         PassManager::new(pass_manager)
     }
 
-    // SubTypes: PassManager<FunctionValue>::create()
-    pub fn create_for_function(module: &Module) -> Self {
+    /// Runs all of this `PassManager`'s passes over a `Module`. Returns `true`
+    /// if any of them modified it; `false` does not indicate an error.
+    pub fn run_on(&self, module: &Module) -> bool {
+        unsafe {
+            LLVMRunPassManager(self.pass_manager, module.module.get()) == 1
+        }
+    }
+}
+
+impl PassManager<FunctionValue> {
+    /// Creates a new `PassManager` which runs its passes over individual functions
+    /// of the given `Module`. This is useful when JITing code one function at a time.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::passes::PassManager;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let void_type = context.void_type();
+    /// let fn_type = void_type.fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    /// let builder = context.create_builder();
+    /// let entry = fn_value.append_basic_block("entry");
+    ///
+    /// builder.position_at_end(&entry);
+    /// builder.build_return(None);
+    ///
+    /// let fpm = PassManager::create_function_pass_manager(&module);
+    ///
+    /// fpm.add_promote_memory_to_register_pass();
+    /// fpm.initialize();
+    /// fpm.run_on(&fn_value);
+    /// fpm.finalize();
+    /// ```
+    pub fn create_function_pass_manager(module: &Module) -> Self {
         let pass_manager = unsafe {
             LLVMCreateFunctionPassManagerForModule(module.module.get())
         };
@@ -136,28 +214,39 @@ impl PassManager {
         PassManager::new(pass_manager)
     }
 
-    // return true means some pass modified the module, not an error occurred
+    /// Runs the initializers of all of this `PassManager`'s passes. This should be called
+    /// once before the first `run_on`. Returns `true` if any of them modified the `Module`,
+    /// not if an error occurred.
     pub fn initialize(&self) -> bool {
         unsafe {
             LLVMInitializeFunctionPassManager(self.pass_manager) == 1
         }
     }
 
+    /// Runs the finalizers of all of this `PassManager`'s passes. This should be called
+    /// once after the last `run_on`. Returns `true` if any of them modified the `Module`.
     pub fn finalize(&self) -> bool {
         unsafe {
             LLVMFinalizeFunctionPassManager(self.pass_manager) == 1
         }
     }
 
-    pub fn run_on_function(&self, fn_value: &FunctionValue) -> bool {
+    /// Runs all of this `PassManager`'s passes over a function in its `Module`.
+    /// Returns `true` if any of them modified it.
+    pub fn run_on(&self, fn_value: &FunctionValue) -> bool {
         unsafe {
             LLVMRunFunctionPassManager(self.pass_manager, fn_value.as_value_ref()) == 1
         }
     }
+}
 
-    pub fn run_on_module(&self, module: &Module) -> bool {
-        unsafe {
-            LLVMRunPassManager(self.pass_manager, module.module.get()) == 1
+impl<T> PassManager<T> {
+    pub(crate) fn new(pass_manager: LLVMPassManagerRef) -> Self {
+        assert!(!pass_manager.is_null());
+
+        PassManager {
+            pass_manager,
+            sub_type: PhantomData,
         }
     }
 
@@ -524,7 +613,7 @@ impl PassManager {
     }
 }
 
-impl Drop for PassManager {
+impl<T> Drop for PassManager<T> {
     fn drop(&mut self) {
         unsafe {
             LLVMDisposePassManager(self.pass_manager)
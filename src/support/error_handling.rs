@@ -1,3 +1,8 @@
+use libc::{c_char, c_void};
+use llvm_sys::LLVMDiagnosticSeverity;
+use llvm_sys::core::{LLVMDisposeMessage, LLVMGetDiagInfoDescription, LLVMGetDiagInfoSeverity};
+use llvm_sys::prelude::LLVMDiagnosticInfoRef;
+
 // Installs an error handler to be called before LLVM exits
 // REVIEW: Maybe it's possible to have a safe wrapper? If we can
 // wrap the provided function input ptr into a &CStr somehow
@@ -32,3 +37,26 @@ pub fn reset_fatal_error_handler() {
         LLVMResetFatalErrorHandler()
     }
 }
+
+// Newer LLVM APIs (ie LLVMLinkModules2) no longer hand back an error string, and instead report
+// errors through the context's diagnostic handler. This handler expects `void_ptr` to be a
+// `*mut *mut c_char` and will write the description of any error diagnostic into it, which
+// must then be freed by the caller (ie via `LLVMString`).
+pub(crate) extern "C" fn get_error_str_diagnostic_handler(diagnostic_info: LLVMDiagnosticInfoRef, void_ptr: *mut c_void) {
+    let severity = unsafe {
+        LLVMGetDiagInfoSeverity(diagnostic_info)
+    };
+
+    if let LLVMDiagnosticSeverity::LLVMDSError = severity {
+        let c_ptr_ptr = void_ptr as *mut *mut c_char;
+
+        // Only the last error is kept, so any earlier description must be freed first
+        unsafe {
+            if !(*c_ptr_ptr).is_null() {
+                LLVMDisposeMessage(*c_ptr_ptr);
+            }
+
+            *c_ptr_ptr = LLVMGetDiagInfoDescription(diagnostic_info);
+        }
+    }
+}
@@ -10,6 +10,9 @@ use std::ffi::{CString, CStr};
 use std::ops::Deref;
 
 /// An owned LLVM String. Also known as a LLVM Message
+///
+/// It derefs to a `CStr`, is displayed as lossily converted UTF-8, and implements `Error`
+/// so that it can be propagated with `?` and boxed as a `Box<Error>`.
 #[derive(Eq)]
 pub struct LLVMString {
     pub(crate) ptr: *const c_char,
@@ -34,7 +37,7 @@ impl LLVMString {
     /// Don't use this if it's not necessary. You likely need to allocate
     /// a CString as input and then LLVM will likely allocate their own string
     /// anyway.
-    fn create(bytes: *const c_char) -> LLVMString {
+    pub(crate) fn create(bytes: *const c_char) -> LLVMString {
         let ptr = unsafe {
             LLVMCreateMessage(bytes)
         };
@@ -61,7 +64,7 @@ impl Debug for LLVMString {
 
 impl Display for LLVMString {
     fn fmt(&self, f: &mut Formatter) -> Result<(), fmt::Error> {
-        write!(f, "{:?}", self.deref())
+        write!(f, "{}", self.to_string_lossy())
     }
 }
 
@@ -73,7 +76,7 @@ impl PartialEq for LLVMString {
 
 impl Error for LLVMString {
     fn description(&self) -> &str {
-        self.to_str().expect("Could not convert LLVMString to str (likely invalid unicode)")
+        self.to_str().unwrap_or("LLVMString contained invalid unicode")
     }
 
     fn cause(&self) -> Option<&Error> {
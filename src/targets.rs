@@ -697,6 +697,24 @@ impl Target {
         Some(TargetMachine::new(target_machine))
     }
 
+    /// Gets the first `Target` registered with LLVM, if any have been initialized.
+    /// Along with `get_next`, this allows iterating over all registered `Target`s.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::targets::{InitializationConfig, Target};
+    ///
+    /// Target::initialize_all(&InitializationConfig::default());
+    ///
+    /// let mut target = Target::get_first();
+    ///
+    /// while let Some(current) = target {
+    ///     println!("{:?}: {:?}", current.get_name(), current.get_description());
+    ///
+    ///     target = current.get_next();
+    /// }
+    /// ```
     pub fn get_first() -> Option<Self> {
         let target = unsafe {
             LLVMGetFirstTarget()
@@ -709,6 +727,7 @@ impl Target {
         Some(Target::new(target))
     }
 
+    /// Gets the `Target` registered after this one, if any.
     pub fn get_next(&self) -> Option<Self> {
         let target = unsafe {
             LLVMGetNextTarget(self.target)
@@ -721,18 +740,21 @@ impl Target {
         Some(Target::new(target))
     }
 
+    /// Gets the short name of this `Target`, ie "x86-64".
     pub fn get_name(&self) -> &CStr {
         unsafe {
             CStr::from_ptr(LLVMGetTargetName(self.target))
         }
     }
 
+    /// Gets a human readable description of this `Target`, ie "64-bit X86: EM64T and AMD64".
     pub fn get_description(&self) -> &CStr {
         unsafe {
             CStr::from_ptr(LLVMGetTargetDescription(self.target))
         }
     }
 
+    /// Looks up a registered `Target` by its short name, ie "x86-64".
     pub fn from_name(name: &str) -> Option<Self> {
         let c_string = CString::new(name).expect("Conversion to CString failed unexpectedly");
 
@@ -752,6 +774,21 @@ impl Target {
 
     }
 
+    /// Looks up the registered `Target` matching a full target triple, returning LLVM's
+    /// error message if none does.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::targets::{InitializationConfig, Target, TargetMachine};
+    ///
+    /// Target::initialize_native(&InitializationConfig::default()).unwrap();
+    ///
+    /// let default_triple = TargetMachine::get_default_triple();
+    /// let target = Target::from_triple(default_triple.to_str().unwrap()).unwrap();
+    ///
+    /// assert!(Target::from_triple("bogus-unknown-unknown").is_err());
+    /// ```
     pub fn from_triple(triple: &str) -> Result<Self, LLVMString> {
         let c_string = CString::new(triple).expect("Conversion to CString failed unexpectedly");
         let mut target = ptr::null_mut();
@@ -768,18 +805,21 @@ impl Target {
         Ok(Target::new(target))
     }
 
+    /// Determines whether or not this `Target` supports JIT compilation.
     pub fn has_jit(&self) -> bool {
         unsafe {
             LLVMTargetHasJIT(self.target) == 1
         }
     }
 
+    /// Determines whether or not a `TargetMachine` can be created for this `Target`.
     pub fn has_target_machine(&self) -> bool {
         unsafe {
             LLVMTargetHasTargetMachine(self.target) == 1
         }
     }
 
+    /// Determines whether or not this `Target` can emit object files.
     pub fn has_asm_backend(&self) -> bool {
         unsafe {
             LLVMTargetHasAsmBackend(self.target) == 1
@@ -817,6 +857,17 @@ impl TargetMachine {
         LLVMString::new(ptr)
     }
 
+    /// Gets the default triple for the host machine, which LLVM was configured to target.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::targets::TargetMachine;
+    ///
+    /// let default_triple = TargetMachine::get_default_triple();
+    ///
+    /// assert!(!default_triple.to_bytes().is_empty());
+    /// ```
     pub fn get_default_triple() -> LLVMString {
         let llvm_string = unsafe {
             LLVMGetDefaultTargetTriple()
@@ -839,6 +890,39 @@ impl TargetMachine {
         }
     }
 
+    /// Creates the `TargetData` describing this `TargetMachine`'s data layout, which can then
+    /// be assigned to a `Module` so that its type layouts match the target.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::OptimizationLevel;
+    /// use inkwell::context::Context;
+    /// use inkwell::targets::{CodeModel, InitializationConfig, RelocMode, Target};
+    ///
+    /// Target::initialize_x86(&InitializationConfig::default());
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let target = Target::from_name("x86-64").unwrap();
+    /// let target_machine = target.create_target_machine("x86_64-pc-linux-gnu", "x86-64", "", OptimizationLevel::Default, RelocMode::Default, CodeModel::Default).unwrap();
+    /// let target_data = target_machine.get_target_data();
+    ///
+    /// module.set_data_layout(&target_data.get_data_layout());
+    ///
+    /// assert_eq!(*module.get_data_layout(), target_data.get_data_layout());
+    /// ```
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+    pub fn get_target_data(&self) -> TargetData {
+        use llvm_sys::target_machine::LLVMCreateTargetDataLayout;
+
+        let target_data = unsafe {
+            LLVMCreateTargetDataLayout(self.target_machine)
+        };
+
+        TargetData::new(target_data)
+    }
+
     pub fn set_asm_verbosity(&self, verbosity: bool) {
         unsafe {
             LLVMSetTargetMachineAsmVerbosity(self.target_machine, verbosity as i32)
@@ -846,12 +930,32 @@ impl TargetMachine {
     }
 
     // TODO: Move to PassManager?
-    pub fn add_analysis_passes(&self, pass_manager: &PassManager) {
+    pub fn add_analysis_passes<T>(&self, pass_manager: &PassManager<T>) {
         unsafe {
             LLVMAddAnalysisPasses(self.target_machine, pass_manager.pass_manager)
         }
     }
 
+    /// Compiles the given `Module` for this `TargetMachine`, writing either assembly or an object file into
+    /// a new `MemoryBuffer` which owns the emitted bytes.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::OptimizationLevel;
+    /// use inkwell::context::Context;
+    /// use inkwell::targets::{CodeModel, FileType, InitializationConfig, RelocMode, Target};
+    ///
+    /// Target::initialize_x86(&InitializationConfig::default());
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let target = Target::from_name("x86-64").unwrap();
+    /// let target_machine = target.create_target_machine("x86_64-pc-linux-gnu", "x86-64", "", OptimizationLevel::Default, RelocMode::Default, CodeModel::Default).unwrap();
+    /// let buffer = target_machine.write_to_memory_buffer(&module, FileType::Object).unwrap();
+    ///
+    /// assert_eq!(&buffer.as_slice()[..4], b"\x7fELF");
+    /// ```
     pub fn write_to_memory_buffer(&self, module: &Module, file_type: FileType) -> Result<MemoryBuffer, LLVMString> {
         let mut memory_buffer = ptr::null_mut();
         let mut err_string = unsafe { zeroed() };
@@ -867,12 +971,32 @@ impl TargetMachine {
         Ok(MemoryBuffer::new(memory_buffer))
     }
 
+    /// Compiles the given `Module` for this `TargetMachine`, writing either assembly or an object file to `path`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::OptimizationLevel;
+    /// use inkwell::context::Context;
+    /// use inkwell::targets::{CodeModel, FileType, InitializationConfig, RelocMode, Target};
+    /// use std::path::Path;
+    ///
+    /// Target::initialize_x86(&InitializationConfig::default());
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let target = Target::from_name("x86-64").unwrap();
+    /// let target_machine = target.create_target_machine("x86_64-pc-linux-gnu", "x86-64", "", OptimizationLevel::Default, RelocMode::Default, CodeModel::Default).unwrap();
+    ///
+    /// assert!(target_machine.write_to_file(&module, FileType::Object, Path::new("my_module.o")).is_ok());
+    /// ```
     pub fn write_to_file(&self, module: &Module, file_type: FileType, path: &Path) -> Result<(), LLVMString> {
         let path = path.to_str().expect("Did not find a valid Unicode path string");
+        let c_string = CString::new(path).expect("Conversion to CString failed unexpectedly");
         let mut err_string = unsafe { zeroed() };
         let return_code = unsafe {
             // REVIEW: Why does LLVM need a mutable ptr to path...?
-            LLVMTargetMachineEmitToFile(self.target_machine, module.module.get(), path.as_ptr() as *mut i8, file_type.as_llvm_file_type(), &mut err_string)
+            LLVMTargetMachineEmitToFile(self.target_machine, module.module.get(), c_string.as_ptr() as *mut i8, file_type.as_llvm_file_type(), &mut err_string)
         };
 
         // TODO: Verify 1 is error code (LLVM can be inconsistent)
@@ -929,7 +1053,7 @@ impl TargetData {
     /// ```
     pub fn ptr_sized_int_type(&self, address_space: Option<AddressSpace>) -> IntType {
         let int_type_ptr = match address_space {
-            Some(address_space) => unsafe { LLVMIntPtrTypeForAS(self.target_data, address_space as u32) },
+            Some(address_space) => unsafe { LLVMIntPtrTypeForAS(self.target_data, u32::from(address_space)) },
             None => unsafe { LLVMIntPtrType(self.target_data) },
         };
 
@@ -954,7 +1078,7 @@ impl TargetData {
     /// ```
     pub fn ptr_sized_int_type_in_context(&self, context: &Context, address_space: Option<AddressSpace>) -> IntType {
         let int_type_ptr = match address_space {
-            Some(address_space) => unsafe { LLVMIntPtrTypeForASInContext(*context.context, self.target_data, address_space as u32) },
+            Some(address_space) => unsafe { LLVMIntPtrTypeForASInContext(*context.context, self.target_data, u32::from(address_space)) },
             None => unsafe { LLVMIntPtrTypeInContext(*context.context, self.target_data) },
         };
 
@@ -976,6 +1100,20 @@ impl TargetData {
         }
     }
 
+    /// Creates a `TargetData` from a data layout string representation. This can be used to
+    /// obtain a `TargetData` for a `Module`'s `DataLayout`, which is just a string otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::targets::TargetData;
+    ///
+    /// let context = Context::create();
+    /// let target_data = TargetData::create("e-m:e-i64:64-f80:128-n8:16:32:64-S128");
+    ///
+    /// assert_eq!(target_data.get_abi_size(&context.i64_type()), 8);
+    /// ```
     // TODOC: This can fail on LLVM's side(exit?), but it doesn't seem like we have any way to check this in rust
     pub fn create(str_repr: &str) -> TargetData {
         let c_string = CString::new(str_repr).expect("Conversion to CString failed unexpectedly");
@@ -998,57 +1136,84 @@ impl TargetData {
         }
     }
 
+    /// Gets the size of a pointer in bytes, either in the given `AddressSpace` or the default one.
     pub fn get_pointer_byte_size(&self, address_space: Option<AddressSpace>) -> u32 {
         match address_space {
-            Some(address_space) => unsafe { LLVMPointerSizeForAS(self.target_data, address_space as u32) },
+            Some(address_space) => unsafe { LLVMPointerSizeForAS(self.target_data, u32::from(address_space)) },
             None => unsafe { LLVMPointerSize(self.target_data) },
         }
     }
 
+    /// Gets the maximum number of bytes that may be overwritten by storing the given type.
     pub fn get_store_size(&self, type_: &AnyType) -> u64 {
         unsafe {
             LLVMStoreSizeOfType(self.target_data, type_.as_type_ref())
         }
     }
 
+    /// Gets the offset in bytes between successive objects of the given type, including alignment padding.
     pub fn get_abi_size(&self, type_: &AnyType) -> u64 {
         unsafe {
             LLVMABISizeOfType(self.target_data, type_.as_type_ref())
         }
     }
 
+    /// Gets the minimum ABI-required alignment in bytes of the given type.
     pub fn get_abi_alignment(&self, type_: &AnyType) -> u32 {
         unsafe {
             LLVMABIAlignmentOfType(self.target_data, type_.as_type_ref())
         }
     }
 
+    /// Gets the minimum alignment in bytes of the given type when placed on the call frame.
     pub fn get_call_frame_alignment(&self, type_: &AnyType) -> u32 {
         unsafe {
             LLVMCallFrameAlignmentOfType(self.target_data, type_.as_type_ref())
         }
     }
 
+    /// Gets the preferred alignment in bytes of the given type.
     pub fn get_preferred_alignment(&self, type_: &AnyType) -> u32 {
         unsafe {
             LLVMPreferredAlignmentOfType(self.target_data, type_.as_type_ref())
         }
     }
 
+    /// Gets the preferred alignment in bytes of the given global.
     pub fn get_preferred_alignment_of_global(&self, value: &GlobalValue) -> u32 {
         unsafe {
             LLVMPreferredAlignmentOfGlobal(self.target_data, value.as_value_ref())
         }
     }
 
+    /// Gets the index of the field of a `StructType` which contains the given byte offset.
+    /// Offsets past the end of the struct resolve to the last field.
     pub fn element_at_offset(&self, struct_type: &StructType, offset: u64) -> u32 {
         unsafe {
             LLVMElementAtOffset(self.target_data, struct_type.as_type_ref(), offset)
         }
     }
 
+    /// Gets the byte offset of a `StructType`'s field at the given index, taking
+    /// any padding into account. Returns `None` if the index is out of bounds.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::targets::TargetData;
+    ///
+    /// let context = Context::create();
+    /// let target_data = TargetData::create("e-m:e-i64:64-f80:128-n8:16:32:64-S128");
+    /// let i8_type = context.i8_type();
+    /// let i32_type = context.i32_type();
+    /// let struct_type = context.struct_type(&[i8_type.into(), i32_type.into()], false);
+    ///
+    /// assert_eq!(target_data.offset_of_element(&struct_type, 1), Some(4));
+    /// assert_eq!(target_data.offset_of_element(&struct_type, 2), None);
+    /// ```
     pub fn offset_of_element(&self, struct_type: &StructType, element: u32) -> Option<u64> {
-        if element > struct_type.count_fields() - 1 {
+        if element >= struct_type.count_fields() {
             return None;
         }
 
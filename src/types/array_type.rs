@@ -1,4 +1,4 @@
-use llvm_sys::core::{LLVMConstArray, LLVMConstNull, LLVMGetArrayLength};
+use llvm_sys::core::{LLVMConstArray, LLVMConstNull, LLVMGetArrayLength, LLVMGetElementType};
 use llvm_sys::prelude::{LLVMTypeRef, LLVMValueRef};
 
 use AddressSpace;
@@ -52,12 +52,28 @@ impl ArrayType {
         self.array_type.array_type(size)
     }
 
+    /// Creates a constant `ArrayValue` from constant values, which must all be of this
+    /// `ArrayType`'s element type. The resulting array has one element per value.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let i32_type = context.i32_type();
+    /// let i32_array_type = i32_type.array_type(3);
+    /// let values = [i32_type.const_int(1, false), i32_type.const_int(2, false), i32_type.const_int(3, false)];
+    /// let i32_array = i32_array_type.const_array(&values);
+    ///
+    /// assert_eq!(i32_array.get_type(), i32_array_type);
+    /// ```
     pub fn const_array<V: BasicValue>(&self, values: &[V]) -> ArrayValue {
         let mut values: Vec<LLVMValueRef> = values.iter()
                                                   .map(|val| val.as_value_ref())
                                                   .collect();
         let value = unsafe {
-            LLVMConstArray(self.as_type_ref(), values.as_mut_ptr(), values.len() as u32)
+            LLVMConstArray(LLVMGetElementType(self.as_type_ref()), values.as_mut_ptr(), values.len() as u32)
         };
 
         ArrayValue::new(value)
@@ -75,6 +91,27 @@ impl ArrayType {
         ArrayValue::new(null)
     }
 
+    /// Gets the element type of this `ArrayType`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let i8_type = context.i8_type();
+    /// let i8_array_type = i8_type.array_type(3);
+    ///
+    /// assert_eq!(i8_array_type.get_element_type().into_int_type(), i8_type);
+    /// ```
+    pub fn get_element_type(&self) -> BasicTypeEnum {
+        let type_ = unsafe {
+            LLVMGetElementType(self.as_type_ref())
+        };
+
+        BasicTypeEnum::new(type_)
+    }
+
     pub fn len(&self) -> u32 {
         unsafe {
             LLVMGetArrayLength(self.as_type_ref())
@@ -2,8 +2,10 @@ use llvm_sys::core::LLVMGetTypeKind;
 use llvm_sys::LLVMTypeKind;
 use llvm_sys::prelude::LLVMTypeRef;
 
-use types::{IntType, VoidType, FunctionType, PointerType, VectorType, ArrayType, StructType, FloatType};
-use types::traits::AsTypeRef;
+use types::{Type, IntType, VoidType, FunctionType, PointerType, VectorType, ArrayType, StructType, FloatType, LabelType, MetadataType, TokenType};
+use types::traits::{AnyType, AsTypeRef};
+
+use std::fmt;
 
 macro_rules! enum_type_set {
     ($enum_name:ident: $($args:ident),*) => (
@@ -24,6 +26,14 @@ macro_rules! enum_type_set {
             }
         }
 
+        impl fmt::Display for $enum_name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let llvm_string = self.print_to_string();
+
+                write!(f, "{}", llvm_string.to_string_lossy())
+            }
+        }
+
         $(
             impl From<$args> for $enum_name {
                 fn from(value: $args) -> $enum_name {
@@ -34,9 +44,74 @@ macro_rules! enum_type_set {
     );
 }
 
-enum_type_set! {AnyTypeEnum: IntType, FunctionType, FloatType, PointerType, StructType, ArrayType, VoidType, VectorType}
+enum_type_set! {AnyTypeEnum: IntType, FunctionType, FloatType, PointerType, StructType, ArrayType, VoidType, VectorType, LabelType, MetadataType, TokenType}
 enum_type_set! {BasicTypeEnum: IntType, FloatType, PointerType, StructType, ArrayType, VectorType}
 
+/// The kind of an LLVM type, as a cheap alternative to matching on an `AnyTypeEnum`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum TypeKind {
+    /// The type with no size or value, ie of functions returning nothing.
+    Void,
+    /// A 16 bit floating point type.
+    Half,
+    /// A 32 bit floating point type.
+    Float,
+    /// A 64 bit floating point type.
+    Double,
+    /// An 80 bit floating point type (X87).
+    X86FP80,
+    /// A 128 bit floating point type (112-bit mantissa).
+    FP128,
+    /// A 128 bit floating point type (two 64-bits, PowerPC).
+    PPCFP128,
+    /// The type of a `BasicBlock` used as a value.
+    Label,
+    /// An arbitrary bit width integer type.
+    Integer,
+    /// A function type.
+    Function,
+    /// A structure type.
+    Struct,
+    /// An array type.
+    Array,
+    /// A pointer type.
+    Pointer,
+    /// A SIMD vector type.
+    Vector,
+    /// The type of metadata arguments.
+    Metadata,
+    /// An X86 MMX vector type.
+    X86MMX,
+    /// The type of values tying instructions together, ie funclet pads.
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7")))]
+    Token,
+}
+
+impl TypeKind {
+    pub(crate) fn new(type_kind: LLVMTypeKind) -> Self {
+        match type_kind {
+            LLVMTypeKind::LLVMVoidTypeKind => TypeKind::Void,
+            LLVMTypeKind::LLVMHalfTypeKind => TypeKind::Half,
+            LLVMTypeKind::LLVMFloatTypeKind => TypeKind::Float,
+            LLVMTypeKind::LLVMDoubleTypeKind => TypeKind::Double,
+            LLVMTypeKind::LLVMX86_FP80TypeKind => TypeKind::X86FP80,
+            LLVMTypeKind::LLVMFP128TypeKind => TypeKind::FP128,
+            LLVMTypeKind::LLVMPPC_FP128TypeKind => TypeKind::PPCFP128,
+            LLVMTypeKind::LLVMLabelTypeKind => TypeKind::Label,
+            LLVMTypeKind::LLVMIntegerTypeKind => TypeKind::Integer,
+            LLVMTypeKind::LLVMFunctionTypeKind => TypeKind::Function,
+            LLVMTypeKind::LLVMStructTypeKind => TypeKind::Struct,
+            LLVMTypeKind::LLVMArrayTypeKind => TypeKind::Array,
+            LLVMTypeKind::LLVMPointerTypeKind => TypeKind::Pointer,
+            LLVMTypeKind::LLVMVectorTypeKind => TypeKind::Vector,
+            LLVMTypeKind::LLVMMetadataTypeKind => TypeKind::Metadata,
+            LLVMTypeKind::LLVMX86_MMXTypeKind => TypeKind::X86MMX,
+            #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7")))]
+            LLVMTypeKind::LLVMTokenTypeKind => TypeKind::Token,
+        }
+    }
+}
+
 
 impl AnyTypeEnum {
     pub(crate) fn new(type_: LLVMTypeRef) -> AnyTypeEnum {
@@ -52,19 +127,39 @@ impl AnyTypeEnum {
             LLVMTypeKind::LLVMX86_FP80TypeKind |
             LLVMTypeKind::LLVMFP128TypeKind |
             LLVMTypeKind::LLVMPPC_FP128TypeKind => AnyTypeEnum::FloatType(FloatType::new(type_)),
-            LLVMTypeKind::LLVMLabelTypeKind => panic!("FIXME: Unsupported type: Label"),
+            LLVMTypeKind::LLVMLabelTypeKind => AnyTypeEnum::LabelType(LabelType::new(type_)),
             LLVMTypeKind::LLVMIntegerTypeKind => AnyTypeEnum::IntType(IntType::new(type_)),
             LLVMTypeKind::LLVMFunctionTypeKind => AnyTypeEnum::FunctionType(FunctionType::new(type_)),
             LLVMTypeKind::LLVMStructTypeKind => AnyTypeEnum::StructType(StructType::new(type_)),
             LLVMTypeKind::LLVMArrayTypeKind => AnyTypeEnum::ArrayType(ArrayType::new(type_)),
             LLVMTypeKind::LLVMPointerTypeKind => AnyTypeEnum::PointerType(PointerType::new(type_)),
             LLVMTypeKind::LLVMVectorTypeKind => AnyTypeEnum::VectorType(VectorType::new(type_)),
-            LLVMTypeKind::LLVMMetadataTypeKind => panic!("FIXME: Unsupported type: Metadata"),
+            LLVMTypeKind::LLVMMetadataTypeKind => AnyTypeEnum::MetadataType(MetadataType::new(type_)),
             LLVMTypeKind::LLVMX86_MMXTypeKind => panic!("FIXME: Unsupported type: MMX"),
             #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7")))]
-            LLVMTypeKind::LLVMTokenTypeKind => panic!("FIXME: Unsupported type: Token"),
+            LLVMTypeKind::LLVMTokenTypeKind => AnyTypeEnum::TokenType(TokenType::new(type_)),
         }
     }
+
+    /// Gets the `TypeKind` of this type, which distinguishes between some types sharing
+    /// a variant of this enum, ie the various `FloatType`s, as well as those without one.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::types::{AnyType, TypeKind};
+    ///
+    /// let context = Context::create();
+    /// let i32_type = context.i32_type();
+    /// let f64_type = context.f64_type();
+    ///
+    /// assert_eq!(i32_type.as_any_type_enum().get_kind(), TypeKind::Integer);
+    /// assert_eq!(f64_type.as_any_type_enum().get_kind(), TypeKind::Double);
+    /// ```
+    pub fn get_kind(&self) -> TypeKind {
+        TypeKind::new(Type::new(self.as_type_ref()).get_kind())
+    }
 }
 
 impl BasicTypeEnum {
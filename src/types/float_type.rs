@@ -31,6 +31,21 @@ impl FloatType {
         self.float_type.array_type(size)
     }
 
+    /// Creates a `VectorType` with this `FloatType` for its element type.
+    /// LLVM does not allow zero sized vectors, so a size of zero will panic.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let f32_type = context.f32_type();
+    /// let f32_vec_type = f32_type.vec_type(4);
+    ///
+    /// assert_eq!(f32_vec_type.get_size(), 4);
+    /// assert_eq!(f32_vec_type.get_element_type().into_float_type(), f32_type);
+    /// ```
     pub fn vec_type(&self, size: u32) -> VectorType {
         self.float_type.vec_type(size)
     }
@@ -154,11 +169,11 @@ impl FloatType {
     /// assert_eq!(x86_f80_type.get_context(), Context::get_global());
     /// ```
     pub fn x86_f80_type() -> FloatType {
-        let f128_type = unsafe {
+        let float_type = unsafe {
             LLVMX86FP80Type()
         };
 
-        FloatType::new(f128_type)
+        FloatType::new(float_type)
     }
 
     /// Creates a new `FloatType` which represents one hundred and twenty eight bits (sixteen bytes) for the global context.
@@ -217,6 +232,19 @@ impl FloatType {
         FloatValue::new(self.float_type.get_undef())
     }
 
+    /// Creates a `GenericValue` of this `FloatType` for use with `ExecutionEngine::run_function`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let f64_type = context.f64_type();
+    /// let generic_value = f64_type.create_generic_value(1.5);
+    ///
+    /// assert_eq!(generic_value.as_float(&f64_type), 1.5);
+    /// ```
     pub fn create_generic_value(&self, value: f64) -> GenericValue {
         let value = unsafe {
             LLVMCreateGenericValueOfFloat(self.as_type_ref(), value)
@@ -1,4 +1,5 @@
-use llvm_sys::core::{LLVMGetParamTypes, LLVMIsFunctionVarArg, LLVMCountParamTypes};
+use llvm_sys::LLVMTypeKind;
+use llvm_sys::core::{LLVMGetParamTypes, LLVMIsFunctionVarArg, LLVMCountParamTypes, LLVMGetReturnType, LLVMGetTypeKind};
 use llvm_sys::prelude::LLVMTypeRef;
 
 use std::fmt;
@@ -24,12 +25,40 @@ impl FunctionType {
         }
     }
 
+    /// Determines whether or not this `FunctionType` takes a variable number of arguments.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let i32_type = context.i32_type();
+    /// let fn_type = i32_type.fn_type(&[i32_type.into()], true);
+    ///
+    /// assert!(fn_type.is_var_arg());
+    /// ```
     pub fn is_var_arg(&self) -> bool {
         unsafe {
             LLVMIsFunctionVarArg(self.as_type_ref()) != 0
         }
     }
 
+    /// Gets the types of the parameters this `FunctionType` takes, excluding any
+    /// variadic arguments.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let i8_type = context.i8_type();
+    /// let f64_type = context.f64_type();
+    /// let fn_type = context.i32_type().fn_type(&[i8_type.into(), f64_type.into()], false);
+    ///
+    /// assert_eq!(fn_type.get_param_types(), vec![i8_type.into(), f64_type.into()]);
+    /// ```
     pub fn get_param_types(&self) -> Vec<BasicTypeEnum> {
         let count = self.count_param_types();
         let mut raw_vec: Vec<LLVMTypeRef> = Vec::with_capacity(count as usize);
@@ -46,12 +75,44 @@ impl FunctionType {
         raw_vec.iter().map(|val| BasicTypeEnum::new(*val)).collect()
     }
 
+    /// Counts the number of parameters this `FunctionType` takes, excluding any
+    /// variadic arguments.
     pub fn count_param_types(&self) -> u32 {
         unsafe {
             LLVMCountParamTypes(self.as_type_ref())
         }
     }
 
+    /// Gets the return type of this `FunctionType`, or `None` if it returns void.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let i32_type = context.i32_type();
+    /// let fn_type = i32_type.fn_type(&[], false);
+    /// let void_fn_type = context.void_type().fn_type(&[], false);
+    ///
+    /// assert_eq!(fn_type.get_return_type(), Some(i32_type.into()));
+    /// assert!(void_fn_type.get_return_type().is_none());
+    /// ```
+    pub fn get_return_type(&self) -> Option<BasicTypeEnum> {
+        let type_ = unsafe {
+            LLVMGetReturnType(self.as_type_ref())
+        };
+
+        let type_kind = unsafe {
+            LLVMGetTypeKind(type_)
+        };
+
+        match type_kind {
+            LLVMTypeKind::LLVMVoidTypeKind => None,
+            _ => Some(BasicTypeEnum::new(type_)),
+        }
+    }
+
     // REVIEW: Always false -> const fn?
     pub fn is_sized(&self) -> bool {
         self.fn_type.is_sized()
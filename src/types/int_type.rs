@@ -9,6 +9,33 @@ use types::traits::AsTypeRef;
 use types::{Type, ArrayType, BasicTypeEnum, VectorType, PointerType, FunctionType};
 use values::{GenericValue, IntValue, PointerValue};
 
+/// How to interpret a string or a digit used to construct an integer constant.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StringRadix {
+    /// Binary 0 or 1
+    Binary = 2,
+    /// Octal 0-7
+    Octal = 8,
+    /// Decimal 0-9
+    Decimal = 10,
+    /// Hexadecimal with upper or lowercase letters up to F.
+    Hexadecimal = 16,
+}
+
+impl StringRadix {
+    /// Is the string valid for the given radix? An optional leading sign is
+    /// allowed, but there must be at least one digit.
+    pub fn matches_str(&self, slice: &str) -> bool {
+        let digits = if slice.starts_with('-') || slice.starts_with('+') {
+            &slice[1..]
+        } else {
+            slice
+        };
+
+        !digits.is_empty() && digits.chars().all(|c| c.is_digit(*self as u32))
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub struct IntType {
     int_type: Type,
@@ -201,16 +228,52 @@ impl IntType {
         IntValue::new(value)
     }
 
-    // TODOC: LLVM will parse as best as it can, without any error for invalid input
-    // ie ("012", 2) => int 1
-    pub fn const_int_from_string(&self, slice: &str, radix: u8) -> IntValue {
+    /// Creates an `IntValue` from a string of digits in the given radix, which may be
+    /// prefixed with a sign. This allows constants wider than 64 bits to be created.
+    /// Returns `None` if the string contains digits which are not valid for the radix.
+    /// Values which are too wide for this `IntType` are truncated.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::types::StringRadix;
+    ///
+    /// let context = Context::create();
+    /// let i8_type = context.i8_type();
+    /// let i8_value = i8_type.const_int_from_string("0121", StringRadix::Decimal).unwrap();
+    ///
+    /// assert_eq!(i8_value.print_to_string().to_string(), "i8 121");
+    /// assert!(i8_type.const_int_from_string("0121", StringRadix::Binary).is_none());
+    /// ```
+    pub fn const_int_from_string(&self, slice: &str, radix: StringRadix) -> Option<IntValue> {
+        if !radix.matches_str(slice) {
+            return None;
+        }
+
         let value = unsafe {
-            LLVMConstIntOfStringAndSize(self.as_type_ref(), slice.as_ptr() as *const i8, slice.len() as u32, radix)
+            LLVMConstIntOfStringAndSize(self.as_type_ref(), slice.as_ptr() as *const i8, slice.len() as u32, radix as u8)
         };
 
-        IntValue::new(value)
+        Some(IntValue::new(value))
     }
 
+    /// Creates an `IntValue` from 64 bit words, ordered from least to most significant.
+    /// This allows constants wider than 64 bits to be created. There should be one word
+    /// for every 64 bits of this `IntType`'s bit width, rounding up: any missing high words
+    /// are treated as zero, and bits beyond the bit width are truncated.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let i128_type = context.i128_type();
+    /// let i128_value = i128_type.const_int_arbitrary_precision(&[0, 1]);
+    ///
+    /// assert_eq!(i128_value.print_to_string().to_string(), "i128 18446744073709551616");
+    /// ```
     pub fn const_int_arbitrary_precision(&self, words: &[u64]) -> IntValue {
         let value = unsafe {
             LLVMConstIntOfArbitraryPrecision(self.as_type_ref(), words.len() as u32, words.as_ptr())
@@ -279,6 +342,21 @@ impl IntType {
         self.int_type.array_type(size)
     }
 
+    /// Creates a `VectorType` with this `IntType` for its element type.
+    /// LLVM does not allow zero sized vectors, so a size of zero will panic.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let i32_type = context.i32_type();
+    /// let i32_vec_type = i32_type.vec_type(4);
+    ///
+    /// assert_eq!(i32_vec_type.get_size(), 4);
+    /// assert_eq!(i32_vec_type.get_element_type().into_int_type(), i32_type);
+    /// ```
     pub fn vec_type(&self, size: u32) -> VectorType {
         self.int_type.vec_type(size)
     }
@@ -320,6 +398,20 @@ impl IntType {
         IntValue::new(self.int_type.get_undef())
     }
 
+    /// Creates a `GenericValue` of this `IntType` for use with `ExecutionEngine::run_function`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let i32_type = context.i32_type();
+    /// let generic_value = i32_type.create_generic_value(42, false);
+    ///
+    /// assert_eq!(generic_value.int_width(), 32);
+    /// assert_eq!(generic_value.as_int(false), 42);
+    /// ```
     pub fn create_generic_value(&self, value: u64, is_signed: bool) -> GenericValue {
         let value = unsafe {
             LLVMCreateGenericValueOfInt(self.as_type_ref(), value, is_signed as i32)
@@ -0,0 +1,54 @@
+use llvm_sys::prelude::LLVMTypeRef;
+
+use context::ContextRef;
+use support::LLVMString;
+use types::traits::AsTypeRef;
+use types::Type;
+
+/// A `LabelType` is the type of a `BasicBlock` when used as a value.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct LabelType {
+    label_type: Type,
+}
+
+impl LabelType {
+    pub(crate) fn new(label_type: LLVMTypeRef) -> Self {
+        assert!(!label_type.is_null());
+
+        LabelType {
+            label_type: Type::new(label_type),
+        }
+    }
+
+    /// Gets a reference to the `Context` this `LabelType` was created in.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let label_type = context.label_type();
+    ///
+    /// assert_eq!(*label_type.get_context(), context);
+    /// ```
+    pub fn get_context(&self) -> ContextRef {
+        self.label_type.get_context()
+    }
+
+    pub fn print_to_string(&self) -> LLVMString {
+        self.label_type.print_to_string()
+    }
+
+    // See Type::print_to_stderr note on 5.0+ status
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm5-0")))]
+    pub fn print_to_stderr(&self) {
+        self.label_type.print_to_stderr()
+    }
+}
+
+impl AsTypeRef for LabelType {
+    fn as_type_ref(&self) -> LLVMTypeRef {
+        self.label_type.type_
+    }
+}
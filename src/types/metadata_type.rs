@@ -0,0 +1,54 @@
+use llvm_sys::prelude::LLVMTypeRef;
+
+use context::ContextRef;
+use support::LLVMString;
+use types::traits::AsTypeRef;
+use types::Type;
+
+/// A `MetadataType` is the type of metadata passed as an argument, e.g. to intrinsics such as `llvm.dbg.value`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct MetadataType {
+    metadata_type: Type,
+}
+
+impl MetadataType {
+    pub(crate) fn new(metadata_type: LLVMTypeRef) -> Self {
+        assert!(!metadata_type.is_null());
+
+        MetadataType {
+            metadata_type: Type::new(metadata_type),
+        }
+    }
+
+    /// Gets a reference to the `Context` this `MetadataType` was created in.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let metadata_type = context.metadata_type();
+    ///
+    /// assert_eq!(*metadata_type.get_context(), context);
+    /// ```
+    pub fn get_context(&self) -> ContextRef {
+        self.metadata_type.get_context()
+    }
+
+    pub fn print_to_string(&self) -> LLVMString {
+        self.metadata_type.print_to_string()
+    }
+
+    // See Type::print_to_stderr note on 5.0+ status
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm5-0")))]
+    pub fn print_to_stderr(&self) {
+        self.metadata_type.print_to_stderr()
+    }
+}
+
+impl AsTypeRef for MetadataType {
+    fn as_type_ref(&self) -> LLVMTypeRef {
+        self.metadata_type.type_
+    }
+}
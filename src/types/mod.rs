@@ -3,19 +3,25 @@ mod enums;
 mod float_type;
 mod fn_type;
 mod int_type;
+mod label_type;
+mod metadata_type;
 mod ptr_type;
 mod struct_type;
+mod token_type;
 mod traits;
 mod vec_type;
 mod void_type;
 
 pub use types::array_type::ArrayType;
-pub use types::enums::{AnyTypeEnum, BasicTypeEnum};
+pub use types::enums::{AnyTypeEnum, BasicTypeEnum, TypeKind};
 pub use types::float_type::FloatType;
 pub use types::fn_type::FunctionType;
-pub use types::int_type::IntType;
+pub use types::int_type::{IntType, StringRadix};
+pub use types::label_type::LabelType;
+pub use types::metadata_type::MetadataType;
 pub use types::ptr_type::PointerType;
 pub use types::struct_type::StructType;
+pub use types::token_type::TokenType;
 pub use types::traits::{AnyType, BasicType, IntMathType, FloatMathType, PointerMathType};
 pub use types::vec_type::VectorType;
 pub use types::void_type::VoidType;
@@ -73,13 +79,15 @@ impl Type {
 
     fn ptr_type(&self, address_space: AddressSpace) -> PointerType {
         let ptr_type = unsafe {
-            LLVMPointerType(self.type_, address_space as u32)
+            LLVMPointerType(self.type_, u32::from(address_space))
         };
 
         PointerType::new(ptr_type)
     }
 
     fn vec_type(&self, size: u32) -> VectorType {
+        assert!(size != 0, "Vectors of size zero are not allowed.");
+
         let vec_type = unsafe {
             LLVMVectorType(self.type_, size)
         };
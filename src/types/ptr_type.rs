@@ -1,11 +1,11 @@
-use llvm_sys::core::{LLVMGetPointerAddressSpace, LLVMConstNull};
+use llvm_sys::core::{LLVMGetPointerAddressSpace, LLVMConstNull, LLVMGetElementType};
 use llvm_sys::prelude::LLVMTypeRef;
 
 use AddressSpace;
 use context::ContextRef;
 use support::LLVMString;
 use types::traits::AsTypeRef;
-use types::{Type, BasicTypeEnum, ArrayType, FunctionType, VectorType};
+use types::{AnyTypeEnum, Type, BasicTypeEnum, ArrayType, FunctionType, VectorType};
 use values::{PointerValue, IntValue};
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
@@ -46,12 +46,49 @@ impl PointerType {
         self.ptr_type.array_type(size)
     }
 
+    /// Gets the `AddressSpace` a `PointerType` points into.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::AddressSpace;
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let i8_type = context.i8_type();
+    /// let i8_ptr_type = i8_type.ptr_type(AddressSpace::Global);
+    ///
+    /// assert_eq!(i8_ptr_type.get_address_space(), AddressSpace::Global);
+    /// ```
     pub fn get_address_space(&self) -> AddressSpace {
         unsafe {
             LLVMGetPointerAddressSpace(self.as_type_ref()).into()
         }
     }
 
+    /// Gets the type this `PointerType` points to. This is an `AnyTypeEnum` rather
+    /// than a `BasicTypeEnum` since pointers may also point to `FunctionType`s.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::AddressSpace;
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let i8_type = context.i8_type();
+    /// let i8_ptr_type = i8_type.ptr_type(AddressSpace::Generic);
+    ///
+    /// assert_eq!(i8_ptr_type.get_element_type().into_int_type(), i8_type);
+    /// ```
+    pub fn get_element_type(&self) -> AnyTypeEnum {
+        let type_ = unsafe {
+            LLVMGetElementType(self.as_type_ref())
+        };
+
+        AnyTypeEnum::new(type_)
+    }
+
     pub fn print_to_string(&self) -> LLVMString {
         self.ptr_type.print_to_string()
     }
@@ -27,6 +27,24 @@ impl StructType {
         }
     }
 
+    /// Gets the type of the field at the given index. Returns `None` if the index
+    /// is out of bounds or if this `StructType` is opaque.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::AddressSpace;
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let i32_type = context.i32_type();
+    /// let i8_ptr_type = context.i8_type().ptr_type(AddressSpace::Generic);
+    /// let struct_type = context.struct_type(&[i32_type.into(), i8_ptr_type.into()], false);
+    ///
+    /// assert_eq!(struct_type.get_field_type_at_index(0).unwrap().into_int_type(), i32_type);
+    /// assert_eq!(struct_type.get_field_type_at_index(1).unwrap().into_pointer_type(), i8_ptr_type);
+    /// assert!(struct_type.get_field_type_at_index(2).is_none());
+    /// ```
     // TODO: Would be great to be able to smartly be able to do this by field name
     #[cfg(not(feature = "llvm3-6"))]
     pub fn get_field_type_at_index(&self, index: u32) -> Option<BasicTypeEnum> {
@@ -49,7 +67,26 @@ impl StructType {
         Some(BasicTypeEnum::new(type_))
     }
 
-    // REVIEW: What's the difference between these two??
+    /// Creates a constant `StructValue` of this `StructType` from constant values,
+    /// which must match its field types. Unlike `StructType::const_struct`, this
+    /// works with named `StructType`s.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let i32_type = context.i32_type();
+    /// let i8_type = context.i8_type();
+    /// let struct_type = context.opaque_struct_type("my_struct");
+    ///
+    /// struct_type.set_body(&[&i32_type, &i8_type], false);
+    ///
+    /// let struct_value = struct_type.const_named_struct(&[i32_type.const_int(1, false).into(), i8_type.const_int(2, false).into()]);
+    ///
+    /// assert_eq!(struct_value.get_type(), struct_type);
+    /// ```
     pub fn const_named_struct(&self, values: &[BasicValueEnum]) -> StructValue {
         let mut args: Vec<LLVMValueRef> = values.iter()
                                                 .map(|val| val.as_value_ref())
@@ -61,6 +98,8 @@ impl StructType {
         StructValue::new(value)
     }
 
+    /// Creates a constant `StructValue` of a new anonymous `StructType` in the global
+    /// `Context` from constant values. See `Context::const_struct` to use another `Context`.
     pub fn const_struct(values: &[BasicValueEnum], packed: bool) -> StructValue {
         let mut args: Vec<LLVMValueRef> = values.iter()
                                                 .map(|val| val.as_value_ref())
@@ -103,6 +142,8 @@ impl StructType {
         self.struct_type.get_context()
     }
 
+    /// Gets the name of this `StructType`. Only named structs, such as those created
+    /// with `Context::opaque_struct_type`, have a name; literal structs return `None`.
     pub fn get_name(&self) -> Option<&CStr> {
         let name = unsafe {
             LLVMGetStructName(self.as_type_ref())
@@ -131,14 +172,15 @@ impl StructType {
         self.struct_type.array_type(size)
     }
 
+    /// Determines whether or not this `StructType`'s fields are packed without any padding.
     pub fn is_packed(&self) -> bool {
         unsafe {
             LLVMIsPackedStruct(self.as_type_ref()) == 1
         }
     }
 
-    // TODO: Worth documenting that a sturct is opaque when types are not
-    // yet assigned (empty array to struct_type)
+    /// Determines whether or not this `StructType` is opaque, which is the case for
+    /// a named struct whose body has not yet been set.
     pub fn is_opaque(&self) -> bool {
         unsafe {
             LLVMIsOpaqueStruct(self.as_type_ref()) == 1
@@ -159,12 +201,14 @@ impl StructType {
         StructType::new(struct_type)
     }
 
+    /// Counts the number of fields in this `StructType`. Opaque structs have none.
     pub fn count_fields(&self) -> u32 {
         unsafe {
             LLVMCountStructElementTypes(self.as_type_ref())
         }
     }
 
+    /// Gets the types of all of this `StructType`'s fields, in order.
     // REVIEW: Method name
     pub fn get_field_types(&self) -> Vec<BasicTypeEnum> {
         let count = self.count_fields();
@@ -196,9 +240,27 @@ impl StructType {
         StructValue::new(self.struct_type.get_undef())
     }
 
+    /// Sets the body of an opaque `StructType`, which allows for recursive types.
+    /// Returns `false` without modifying the struct if it already has a body. Note
+    /// that setting an empty body still makes the struct non-opaque.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::AddressSpace;
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let node_type = context.opaque_struct_type("Node");
+    /// let i32_type = context.i32_type();
+    /// let node_ptr_type = node_type.ptr_type(AddressSpace::Generic);
+    ///
+    /// assert!(node_type.set_body(&[&i32_type, &node_ptr_type], false));
+    /// assert!(!node_type.is_opaque());
+    /// assert_eq!(node_type.count_fields(), 2);
+    /// ```
     // REVIEW: SubTypes should allow this to only be implemented for StructType<Opaque> one day
     // but would have to return StructType<Tys>
-    // REVIEW: What happens if called with &[]? Should that still be opaque? Does the call break?
     pub fn set_body(&self, field_types: &[&BasicType], packed: bool) -> bool {
         let is_opaque = self.is_opaque();
         let mut field_types: Vec<LLVMTypeRef> = field_types.iter()
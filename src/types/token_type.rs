@@ -0,0 +1,55 @@
+use llvm_sys::prelude::LLVMTypeRef;
+
+use context::ContextRef;
+use support::LLVMString;
+use types::traits::AsTypeRef;
+use types::Type;
+
+/// A `TokenType` is the type of values which tie instructions together, e.g. funclet pads in
+/// funclet based exception handling. Its values cannot be stored, merged by phis, or selected.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct TokenType {
+    token_type: Type,
+}
+
+impl TokenType {
+    pub(crate) fn new(token_type: LLVMTypeRef) -> Self {
+        assert!(!token_type.is_null());
+
+        TokenType {
+            token_type: Type::new(token_type),
+        }
+    }
+
+    /// Gets a reference to the `Context` this `TokenType` was created in.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let token_type = context.token_type();
+    ///
+    /// assert_eq!(*token_type.get_context(), context);
+    /// ```
+    pub fn get_context(&self) -> ContextRef {
+        self.token_type.get_context()
+    }
+
+    pub fn print_to_string(&self) -> LLVMString {
+        self.token_type.print_to_string()
+    }
+
+    // See Type::print_to_stderr note on 5.0+ status
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm5-0")))]
+    pub fn print_to_stderr(&self) {
+        self.token_type.print_to_stderr()
+    }
+}
+
+impl AsTypeRef for TokenType {
+    fn as_type_ref(&self) -> LLVMTypeRef {
+        self.token_type.type_
+    }
+}
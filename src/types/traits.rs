@@ -2,7 +2,10 @@ use llvm_sys::prelude::LLVMTypeRef;
 
 use std::fmt::Debug;
 
-use types::{IntType, FunctionType, FloatType, PointerType, StructType, ArrayType, VectorType, VoidType, Type};
+use AddressSpace;
+use support::LLVMString;
+
+use types::{IntType, FunctionType, FloatType, PointerType, StructType, ArrayType, VectorType, VoidType, LabelType, MetadataType, TokenType, Type};
 use types::enums::{AnyTypeEnum, BasicTypeEnum};
 use values::{IntMathValue, FloatMathValue, PointerMathValue, IntValue, FloatValue, PointerValue, VectorValue};
 
@@ -27,6 +30,45 @@ pub trait AnyType: AsTypeRef + Debug {
     fn as_any_type_enum(&self) -> AnyTypeEnum {
         AnyTypeEnum::new(self.as_type_ref())
     }
+
+    /// Determines whether or not this type has a size, which is required of allocas and
+    /// size queries. Opaque structs, functions, void and labels are examples of unsized types.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::types::AnyType;
+    ///
+    /// let context = Context::create();
+    /// let struct_type = context.struct_type(&[context.i32_type().into()], false);
+    /// let opaque_struct_type = context.opaque_struct_type("my_struct");
+    ///
+    /// assert!(struct_type.as_any_type_enum().is_sized());
+    /// assert!(!opaque_struct_type.as_any_type_enum().is_sized());
+    /// ```
+    fn is_sized(&self) -> bool {
+        Type::new(self.as_type_ref()).is_sized()
+    }
+
+    /// Prints the textual representation of this type to an `LLVMString`, without it
+    /// needing to be part of a `Module`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::types::AnyType;
+    ///
+    /// let context = Context::create();
+    /// let array_type = context.i32_type().array_type(4);
+    ///
+    /// assert_eq!(array_type.as_any_type_enum().print_to_string().to_str(), Ok("[4 x i32]"));
+    /// assert_eq!(array_type.as_any_type_enum().to_string(), "[4 x i32]");
+    /// ```
+    fn print_to_string(&self) -> LLVMString {
+        Type::new(self.as_type_ref()).print_to_string()
+    }
 }
 
 /// Represents a basic LLVM type, that may be used in functions and struct declarations.
@@ -40,6 +82,11 @@ pub trait BasicType: AnyType {
     fn fn_type(&self, param_types: &[BasicTypeEnum], is_var_args: bool) -> FunctionType {
         Type::new(self.as_type_ref()).fn_type(param_types, is_var_args)
     }
+
+    /// Creates a `PointerType` pointing to the current type in the given `AddressSpace`.
+    fn ptr_type(&self, address_space: AddressSpace) -> PointerType {
+        Type::new(self.as_type_ref()).ptr_type(address_space)
+    }
 }
 
 /// Represents an LLVM type that can have integer math operations applied to it.
@@ -61,7 +108,7 @@ pub trait PointerMathType: BasicType {
     type PtrConvType: IntMathType;
 }
 
-trait_type_set! {AnyType: AnyTypeEnum, BasicTypeEnum, IntType, FunctionType, FloatType, PointerType, StructType, ArrayType, VoidType, VectorType}
+trait_type_set! {AnyType: AnyTypeEnum, BasicTypeEnum, IntType, FunctionType, FloatType, PointerType, StructType, ArrayType, VoidType, VectorType, LabelType, MetadataType, TokenType}
 trait_type_set! {BasicType: BasicTypeEnum, IntType, FloatType, PointerType, StructType, ArrayType, VectorType}
 
 impl IntMathType for IntType {
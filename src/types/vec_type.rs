@@ -1,9 +1,9 @@
-use llvm_sys::core::{LLVMConstVector, LLVMConstNull, LLVMGetVectorSize};
+use llvm_sys::core::{LLVMConstVector, LLVMConstNull, LLVMGetVectorSize, LLVMGetElementType};
 use llvm_sys::prelude::{LLVMTypeRef, LLVMValueRef};
 
 use support::LLVMString;
 use types::traits::AsTypeRef;
-use types::Type;
+use types::{Type, BasicTypeEnum};
 use values::{BasicValue, PointerValue, VectorValue, IntValue};
 
 // REVIEW: vec_type() is impl for IntType & FloatType. Need to
@@ -44,6 +44,27 @@ impl VectorType {
         }
     }
 
+    /// Gets the element type of this `VectorType`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let f32_type = context.f32_type();
+    /// let f32_vec_type = f32_type.vec_type(4);
+    ///
+    /// assert_eq!(f32_vec_type.get_element_type().into_float_type(), f32_type);
+    /// ```
+    pub fn get_element_type(&self) -> BasicTypeEnum {
+        let type_ = unsafe {
+            LLVMGetElementType(self.as_type_ref())
+        };
+
+        BasicTypeEnum::new(type_)
+    }
+
     // REVIEW:
     // TypeSafety v2 (GH Issue #8) could help here by constraining
     // sub-types to be the same across the board. For now, we could
@@ -0,0 +1,102 @@
+use llvm_sys::core::{LLVMGetNextUse, LLVMGetUser, LLVMGetUsedValue};
+use llvm_sys::prelude::LLVMUseRef;
+
+use values::{AnyValueEnum, BasicValueEnum};
+
+/// A usage of a `BasicValue` in another value, such as an operand of an instruction.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct BasicValueUse {
+    use_: LLVMUseRef,
+}
+
+impl BasicValueUse {
+    pub(crate) fn new(use_: LLVMUseRef) -> Self {
+        assert!(!use_.is_null());
+
+        BasicValueUse {
+            use_,
+        }
+    }
+
+    /// Gets the next use of the same `BasicValue`, if any.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::values::BasicValue;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    /// let entry = fn_value.append_basic_block("entry");
+    ///
+    /// builder.position_at_end(&entry);
+    ///
+    /// let param = fn_value.get_first_param().unwrap().into_int_value();
+    /// let sum = builder.build_int_add(param, param, "sum");
+    ///
+    /// builder.build_return(Some(&sum));
+    ///
+    /// // Both operands of the add are uses of the param
+    /// let first_use = param.get_first_use().unwrap();
+    ///
+    /// assert!(first_use.get_next_use().is_some());
+    /// assert!(first_use.get_next_use().unwrap().get_next_use().is_none());
+    /// ```
+    pub fn get_next_use(&self) -> Option<Self> {
+        let use_ = unsafe {
+            LLVMGetNextUse(self.use_)
+        };
+
+        if use_.is_null() {
+            return None;
+        }
+
+        Some(BasicValueUse::new(use_))
+    }
+
+    /// Gets the value which contains this use, such as an instruction with the used
+    /// value as an operand.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::values::BasicValue;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    /// let entry = fn_value.append_basic_block("entry");
+    ///
+    /// builder.position_at_end(&entry);
+    ///
+    /// let param = fn_value.get_first_param().unwrap();
+    /// let ret = builder.build_return(Some(&param));
+    ///
+    /// assert_eq!(param.get_first_use().unwrap().get_user().into_instruction_value(), ret);
+    /// ```
+    pub fn get_user(&self) -> AnyValueEnum {
+        let user = unsafe {
+            LLVMGetUser(self.use_)
+        };
+
+        AnyValueEnum::new(user)
+    }
+
+    /// Gets the value being used, which is the `BasicValue` this use was obtained from.
+    pub fn get_used_value(&self) -> BasicValueEnum {
+        let used_value = unsafe {
+            LLVMGetUsedValue(self.use_)
+        };
+
+        BasicValueEnum::new(used_value)
+    }
+}
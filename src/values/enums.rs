@@ -1,4 +1,4 @@
-use llvm_sys::core::{LLVMTypeOf, LLVMGetTypeKind};
+use llvm_sys::core::{LLVMTypeOf, LLVMGetTypeKind, LLVMIsAInstruction};
 use llvm_sys::LLVMTypeKind;
 use llvm_sys::prelude::LLVMValueRef;
 
@@ -62,7 +62,18 @@ impl AnyValueEnum {
             LLVMTypeKind::LLVMArrayTypeKind => AnyValueEnum::ArrayValue(ArrayValue::new(value)),
             LLVMTypeKind::LLVMVectorTypeKind => AnyValueEnum::VectorValue(VectorValue::new(value)),
             LLVMTypeKind::LLVMFunctionTypeKind => AnyValueEnum::FunctionValue(FunctionValue::new(value).unwrap()),
-            LLVMTypeKind::LLVMVoidTypeKind => panic!("Void values shouldn't exist."),
+            LLVMTypeKind::LLVMVoidTypeKind => {
+                let instruction = unsafe {
+                    LLVMIsAInstruction(value)
+                };
+
+                // Instructions such as store and void calls have void type
+                if instruction.is_null() {
+                    panic!("Void values shouldn't exist.");
+                }
+
+                AnyValueEnum::InstructionValue(InstructionValue::new(value))
+            },
             LLVMTypeKind::LLVMMetadataTypeKind => panic!("Metadata values are not supported as AnyValue's."),
             _ => panic!("The given type is not supported.")
         }
@@ -1,19 +1,23 @@
 use llvm_sys::analysis::{LLVMVerifierFailureAction, LLVMVerifyFunction, LLVMViewFunctionCFG, LLVMViewFunctionCFGOnly};
-use llvm_sys::core::{LLVMIsAFunction, LLVMIsConstant, LLVMGetLinkage, LLVMTypeOf, LLVMGetPreviousFunction, LLVMGetNextFunction, LLVMGetParam, LLVMCountParams, LLVMGetLastParam, LLVMCountBasicBlocks, LLVMGetFirstParam, LLVMGetNextParam, LLVMGetBasicBlocks, LLVMGetReturnType, LLVMAppendBasicBlock, LLVMDeleteFunction, LLVMGetElementType, LLVMGetLastBasicBlock, LLVMGetFirstBasicBlock, LLVMGetEntryBasicBlock, LLVMGetIntrinsicID, LLVMGetFunctionCallConv, LLVMSetFunctionCallConv, LLVMGetGC, LLVMSetGC, LLVMSetLinkage};
+use llvm_sys::core::{LLVMIsAFunction, LLVMIsConstant, LLVMGetLinkage, LLVMTypeOf, LLVMGetPreviousFunction, LLVMGetNextFunction, LLVMGetParam, LLVMCountParams, LLVMGetLastParam, LLVMCountBasicBlocks, LLVMGetFirstParam, LLVMGetNextParam, LLVMGetBasicBlocks, LLVMGetReturnType, LLVMAppendBasicBlock, LLVMDeleteFunction, LLVMGetElementType, LLVMGetLastBasicBlock, LLVMGetFirstBasicBlock, LLVMGetEntryBasicBlock, LLVMGetIntrinsicID, LLVMGetFunctionCallConv, LLVMSetFunctionCallConv, LLVMGetGC, LLVMSetGC, LLVMSetLinkage, LLVMGetDLLStorageClass, LLVMSetDLLStorageClass, LLVMGetVisibility, LLVMSetVisibility, LLVMGetParams, LLVMIsDeclaration};
 #[cfg(not(feature = "llvm3-6"))]
 use llvm_sys::core::{LLVMGetPersonalityFn, LLVMSetPersonalityFn};
 use llvm_sys::prelude::{LLVMValueRef, LLVMBasicBlockRef};
+use either::Either;
 
 use std::ffi::{CStr, CString};
 use std::mem::forget;
 use std::fmt;
 
+#[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+use attributes::{Attribute, AttributeLoc};
+use {DLLStorageClass, GlobalVisibility};
 use basic_block::BasicBlock;
 use module::Linkage;
 use support::LLVMString;
 use types::{BasicTypeEnum, FunctionType};
 use values::traits::AsValueRef;
-use values::{BasicValueEnum, Value, MetadataValue};
+use values::{BasicValueEnum, PointerValue, Value, MetadataValue};
 
 #[derive(PartialEq, Eq, Clone, Copy)]
 pub struct FunctionValue {
@@ -47,6 +51,70 @@ impl FunctionValue {
         }
     }
 
+    /// Gets the `DLLStorageClass` of this `FunctionValue`.
+    pub fn get_dll_storage_class(&self) -> DLLStorageClass {
+        let dll_storage_class = unsafe {
+            LLVMGetDLLStorageClass(self.as_value_ref())
+        };
+
+        DLLStorageClass::new(dll_storage_class)
+    }
+
+    /// Sets the `DLLStorageClass` of this `FunctionValue`, independently of its `Linkage`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::DLLStorageClass;
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    ///
+    /// fn_value.set_dll_storage_class(DLLStorageClass::Export);
+    ///
+    /// assert_eq!(fn_value.get_dll_storage_class(), DLLStorageClass::Export);
+    /// ```
+    pub fn set_dll_storage_class(&self, dll_storage_class: DLLStorageClass) {
+        unsafe {
+            LLVMSetDLLStorageClass(self.as_value_ref(), dll_storage_class.as_llvm_class())
+        }
+    }
+
+    /// Gets the `GlobalVisibility` of this `FunctionValue`.
+    pub fn get_visibility(&self) -> GlobalVisibility {
+        let visibility = unsafe {
+            LLVMGetVisibility(self.as_value_ref())
+        };
+
+        GlobalVisibility::new(visibility)
+    }
+
+    /// Sets the `GlobalVisibility` of this `FunctionValue`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::GlobalVisibility;
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    ///
+    /// fn_value.set_visibility(GlobalVisibility::Hidden);
+    ///
+    /// assert_eq!(fn_value.get_visibility(), GlobalVisibility::Hidden);
+    /// ```
+    pub fn set_visibility(&self, visibility: GlobalVisibility) {
+        unsafe {
+            LLVMSetVisibility(self.as_value_ref(), visibility.as_llvm_visibility())
+        }
+    }
+
     pub fn is_null(&self) -> bool {
         self.fn_value.is_null()
     }
@@ -63,6 +131,31 @@ impl FunctionValue {
         self.fn_value.print_to_stderr()
     }
 
+    /// Verifies this `FunctionValue` on its own, which can be used to catch malformed
+    /// functions as soon as they are built rather than once the whole `Module` is done.
+    /// Returns `true` if it is valid. If `print` is `true`, LLVM will also print any
+    /// problems it finds to stderr.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    /// let entry = fn_value.append_basic_block("entry");
+    ///
+    /// // Missing a terminator:
+    /// assert!(!fn_value.verify(false));
+    ///
+    /// builder.position_at_end(&entry);
+    /// builder.build_return(None);
+    ///
+    /// assert!(fn_value.verify(false));
+    /// ```
     // TODO: Maybe support LLVMAbortProcessAction?
     // FIXME: Better error returns, code 1 is error
     pub fn verify(&self, print: bool) -> bool {
@@ -79,7 +172,23 @@ impl FunctionValue {
         code != 1
     }
 
-    // REVIEW: If there's a demand, could easily create a module.get_functions() -> Iterator
+    /// Gets the `FunctionValue` following this one in its `Module`, if any.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let void_type = context.void_type();
+    /// let fn_type = void_type.fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    /// let fn_value2 = module.add_function("my_fn2", &fn_type, None);
+    ///
+    /// assert_eq!(fn_value.get_next_function().unwrap(), fn_value2);
+    /// assert!(fn_value2.get_next_function().is_none());
+    /// ```
     pub fn get_next_function(&self) -> Option<Self> {
         let function = unsafe {
             LLVMGetNextFunction(self.as_value_ref())
@@ -88,6 +197,23 @@ impl FunctionValue {
         FunctionValue::new(function)
     }
 
+    /// Gets the `FunctionValue` preceding this one in its `Module`, if any.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let void_type = context.void_type();
+    /// let fn_type = void_type.fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    /// let fn_value2 = module.add_function("my_fn2", &fn_type, None);
+    ///
+    /// assert_eq!(fn_value2.get_previous_function().unwrap(), fn_value);
+    /// assert!(fn_value.get_previous_function().is_none());
+    /// ```
     pub fn get_previous_function(&self) -> Option<Self> {
         let function = unsafe {
             LLVMGetPreviousFunction(self.as_value_ref())
@@ -120,11 +246,13 @@ impl FunctionValue {
         Some(BasicValueEnum::new(param))
     }
 
-    // REVIEW: Odd behavior, a function with no BBs returns a ptr
-    // that isn't actually a basic_block and seems to get corrupted
-    // Should check filed LLVM bugs - maybe just return None since
-    // we can catch it with "LLVMIsABasicBlock"
+    /// Gets the entry `BasicBlock` of this `FunctionValue`, which is always its first
+    /// `BasicBlock`. Returns `None` if this `FunctionValue` has no `BasicBlock`s.
     pub fn get_entry_basic_block(&self) -> Option<BasicBlock> {
+        // LLVM doesn't check whether a function has any basic blocks before
+        // getting its entry block and instead hands back a dangling pointer
+        self.get_first_basic_block()?;
+
         let bb = unsafe {
             LLVMGetEntryBasicBlock(self.as_value_ref())
         };
@@ -132,10 +260,7 @@ impl FunctionValue {
         BasicBlock::new(bb)
     }
 
-    // REVIEW: Odd behavior, a function with no BBs returns a ptr
-    // that isn't actually a basic_block and seems to get corrupted
-    // Should check filed LLVM bugs - maybe just return None since
-    // we can catch it with "LLVMIsABasicBlock"
+    /// Gets the first `BasicBlock` of this `FunctionValue`, if any.
     pub fn get_first_basic_block(&self) -> Option<BasicBlock> {
         let bb = unsafe {
             LLVMGetFirstBasicBlock(self.as_value_ref())
@@ -156,10 +281,11 @@ impl FunctionValue {
         BasicBlock::new(bb).expect("Appending basic block should never fail")
     }
 
+    /// Gets the nth parameter of this `FunctionValue`, or `None` if it is out of bounds.
     pub fn get_nth_param(&self, nth: u32) -> Option<BasicValueEnum> {
         let count = self.count_params();
 
-        if nth + 1 > count {
+        if nth >= count {
             return None;
         }
 
@@ -170,18 +296,64 @@ impl FunctionValue {
         Some(BasicValueEnum::new(param))
     }
 
+    /// Counts the number of parameters this `FunctionValue` takes.
     pub fn count_params(&self) -> u32 {
         unsafe {
             LLVMCountParams(self.fn_value.value)
         }
     }
 
+    /// Counts the number of `BasicBlock`s in this `FunctionValue`.
     pub fn count_basic_blocks(&self) -> u32 {
         unsafe {
             LLVMCountBasicBlocks(self.as_value_ref())
         }
     }
 
+    /// Determines whether or not this `FunctionValue` is only a declaration, which is
+    /// to say it has no body and is expected to be defined elsewhere.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    ///
+    /// assert!(fn_value.is_declaration());
+    ///
+    /// fn_value.append_basic_block("entry");
+    ///
+    /// assert!(!fn_value.is_declaration());
+    /// ```
+    pub fn is_declaration(&self) -> bool {
+        unsafe {
+            LLVMIsDeclaration(self.as_value_ref()) == 1
+        }
+    }
+
+    /// Gets all of the `BasicBlock`s in this `FunctionValue`, in order.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    /// let entry = fn_value.append_basic_block("entry");
+    /// let exit = fn_value.append_basic_block("exit");
+    ///
+    /// assert_eq!(fn_value.count_basic_blocks(), 2);
+    /// assert_eq!(fn_value.get_entry_basic_block().unwrap(), entry);
+    /// assert_eq!(fn_value.get_last_basic_block().unwrap(), exit);
+    /// assert_eq!(fn_value.get_basic_blocks(), vec![entry, exit]);
+    /// ```
     pub fn get_basic_blocks(&self) -> Vec<BasicBlock> {
         let count = self.count_basic_blocks();
         let mut raw_vec: Vec<LLVMBasicBlockRef> = Vec::with_capacity(count as usize);
@@ -206,13 +378,57 @@ impl FunctionValue {
         BasicTypeEnum::new(type_)
     }
 
+    /// Gets all of the parameters of this `FunctionValue`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let i32_type = context.i32_type();
+    /// let fn_type = i32_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    /// let params = fn_value.get_params();
+    ///
+    /// assert_eq!(params.len(), 2);
+    ///
+    /// params[0].into_int_value().set_name("a");
+    /// params[1].into_int_value().set_name("b");
+    /// ```
+    pub fn get_params(&self) -> Vec<BasicValueEnum> {
+        let count = self.count_params();
+        let mut raw_vec: Vec<LLVMValueRef> = Vec::with_capacity(count as usize);
+        let ptr = raw_vec.as_mut_ptr();
+
+        forget(raw_vec);
+
+        let raw_vec = unsafe {
+            LLVMGetParams(self.as_value_ref(), ptr);
+
+            Vec::from_raw_parts(ptr, count as usize, count as usize)
+        };
+
+        raw_vec.iter().map(|val| BasicValueEnum::new(*val)).collect()
+    }
+
+    /// Gets an iterator over the parameters of this `FunctionValue`.
+    #[deprecated(note = "use get_params, or get_param_iter to avoid allocating")]
     pub fn params(&self) -> ParamValueIter {
+        self.get_param_iter()
+    }
+
+    /// Gets an iterator over the parameters of this `FunctionValue`, which
+    /// lazily walks them without allocating.
+    pub fn get_param_iter(&self) -> ParamValueIter {
         ParamValueIter {
             param_iter_value: self.fn_value.value,
             start: true,
         }
     }
 
+    /// Gets the last `BasicBlock` of this `FunctionValue`, if any.
     pub fn get_last_basic_block(&self) -> Option<BasicBlock> {
         let bb = unsafe {
             LLVMGetLastBasicBlock(self.fn_value.value)
@@ -237,11 +453,29 @@ impl FunctionValue {
         }
     }
 
+    /// Removes this `FunctionValue` from its `Module` and deletes it. This is unsafe because
+    /// `FunctionValue`s are `Copy`, so any remaining copies will be left dangling.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let void_type = context.void_type();
+    /// let fn_type = void_type.fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    ///
+    /// unsafe {
+    ///     fn_value.delete();
+    /// }
+    ///
+    /// assert!(module.get_function("my_fn").is_none());
+    /// ```
     // FIXME: Look for ways to prevent use after delete
     pub unsafe fn delete(self) {
-        // unsafe {
         LLVMDeleteFunction(self.as_value_ref())
-        // }
     }
 
     pub fn get_type(&self) -> FunctionType {
@@ -260,7 +494,8 @@ impl FunctionValue {
         self.fn_value.set_metadata(metadata, kind_id)
     }
 
-    // TODOC: How this works as an exception handler
+    /// Determines whether or not this `FunctionValue` has a personality function, which
+    /// the unwinder calls to decide how exceptions are handled by its landingpads.
     #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
     pub fn has_personality_function(&self) -> bool {
         use llvm_sys::core::LLVMHasPersonalityFn;
@@ -270,8 +505,38 @@ impl FunctionValue {
         }
     }
 
+    /// Gets the personality function of this `FunctionValue`, if any.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let i32_type = context.i32_type();
+    /// let personality_type = i32_type.fn_type(&[], true);
+    /// let personality = module.add_function("__gxx_personality_v0", &personality_type, None);
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    ///
+    /// assert!(fn_value.get_personality_function().is_none());
+    ///
+    /// fn_value.set_personality_function(&personality);
+    ///
+    /// assert_eq!(fn_value.get_personality_function().unwrap(), personality);
+    /// ```
+    // REVIEW: LLVM assumes a personality function is present here and will segfault
+    // otherwise. LLVM 3.7 & 3.8 have no way to check for one beforehand
     #[cfg(not(feature = "llvm3-6"))]
     pub fn get_personality_function(&self) -> Option<FunctionValue> {
+        #[cfg(not(any(feature = "llvm3-7", feature = "llvm3-8")))]
+        {
+            if !self.has_personality_function() {
+                return None;
+            }
+        }
+
         let value = unsafe {
             LLVMGetPersonalityFn(self.as_value_ref())
         };
@@ -279,6 +544,26 @@ impl FunctionValue {
         FunctionValue::new(value)
     }
 
+    /// Sets the personality function of this `FunctionValue`, such as `__gxx_personality_v0`
+    /// for C++ compatible exceptions. Functions containing landingpads need one.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let i32_type = context.i32_type();
+    /// let personality_type = i32_type.fn_type(&[], true);
+    /// let personality = module.add_function("__gxx_personality_v0", &personality_type, None);
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    ///
+    /// fn_value.set_personality_function(&personality);
+    ///
+    /// assert!(fn_value.has_personality_function());
+    /// ```
     #[cfg(not(feature = "llvm3-6"))]
     pub fn set_personality_function(&self, personality_fn: &FunctionValue) {
         unsafe {
@@ -292,24 +577,183 @@ impl FunctionValue {
         }
     }
 
+    /// Gets the calling convention of this `FunctionValue` as its raw LLVM id.
+    /// See `set_call_conventions` for a list of common ids.
     pub fn get_call_conventions(&self) -> u32 {
         unsafe {
             LLVMGetFunctionCallConv(self.as_value_ref())
         }
     }
 
+    /// Sets the calling convention of this `FunctionValue` from its raw LLVM id.
+    /// Common ids include `0` (ccc, the C convention and default), `8` (fastcc),
+    /// `9` (coldcc), `10` (ghccc), `64` (x86_stdcallcc) and `65` (x86_fastcallcc).
+    /// Call sites should use the same convention, see `InstructionValue::set_call_conventions`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_module");
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let function = module.add_function("my_fn", &fn_type, None);
+    ///
+    /// assert_eq!(function.get_call_conventions(), 0);
+    ///
+    /// function.set_call_conventions(8);
+    ///
+    /// assert_eq!(function.get_call_conventions(), 8);
+    /// ```
     pub fn set_call_conventions(&self, call_conventions: u32) {
         unsafe {
             LLVMSetFunctionCallConv(self.as_value_ref(), call_conventions)
         }
     }
 
+    /// Adds an `Attribute` to this `FunctionValue` at the given `AttributeLoc`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::attributes::AttributeLoc;
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let void_type = context.void_type();
+    /// let fn_type = void_type.fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    /// let string_attribute = context.create_string_attribute("my_key", "my_val");
+    ///
+    /// fn_value.add_attribute(AttributeLoc::Function, string_attribute);
+    /// ```
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+    pub fn add_attribute(&self, loc: AttributeLoc, attribute: Attribute) {
+        use llvm_sys::core::LLVMAddAttributeAtIndex;
+
+        unsafe {
+            LLVMAddAttributeAtIndex(self.as_value_ref(), loc.get_index(), attribute.attribute)
+        }
+    }
+
+    /// Counts the number of `Attribute`s belonging to the given `AttributeLoc` on this `FunctionValue`.
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+    pub fn count_attributes(&self, loc: AttributeLoc) -> u32 {
+        use llvm_sys::core::LLVMGetAttributeCountAtIndex;
+
+        unsafe {
+            LLVMGetAttributeCountAtIndex(self.as_value_ref(), loc.get_index())
+        }
+    }
+
+    /// Gets the enum `Attribute` with the given kind id at the given `AttributeLoc`, if it exists.
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+    pub fn get_enum_attribute(&self, loc: AttributeLoc, kind_id: u32) -> Option<Attribute> {
+        use llvm_sys::core::LLVMGetEnumAttributeAtIndex;
+
+        let ptr = unsafe {
+            LLVMGetEnumAttributeAtIndex(self.as_value_ref(), loc.get_index(), kind_id)
+        };
+
+        if ptr.is_null() {
+            return None;
+        }
+
+        Some(Attribute::new(ptr))
+    }
+
+    /// Gets the string `Attribute` with the given key at the given `AttributeLoc`, if it exists.
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+    pub fn get_string_attribute(&self, loc: AttributeLoc, key: &str) -> Option<Attribute> {
+        use llvm_sys::core::LLVMGetStringAttributeAtIndex;
+
+        let ptr = unsafe {
+            LLVMGetStringAttributeAtIndex(self.as_value_ref(), loc.get_index(), key.as_ptr() as *const _, key.len() as u32)
+        };
+
+        if ptr.is_null() {
+            return None;
+        }
+
+        Some(Attribute::new(ptr))
+    }
+
+    /// Removes the enum `Attribute` with the given kind id at the given `AttributeLoc`.
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+    pub fn remove_enum_attribute(&self, loc: AttributeLoc, kind_id: u32) {
+        use llvm_sys::core::LLVMRemoveEnumAttributeAtIndex;
+
+        unsafe {
+            LLVMRemoveEnumAttributeAtIndex(self.as_value_ref(), loc.get_index(), kind_id)
+        }
+    }
+
+    /// Removes the string `Attribute` with the given key at the given `AttributeLoc`.
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+    pub fn remove_string_attribute(&self, loc: AttributeLoc, key: &str) {
+        use llvm_sys::core::LLVMRemoveStringAttributeAtIndex;
+
+        unsafe {
+            LLVMRemoveStringAttributeAtIndex(self.as_value_ref(), loc.get_index(), key.as_ptr() as *const _, key.len() as u32)
+        }
+    }
+
+    /// Gets the name of the garbage collector strategy used by this `FunctionValue`.
+    /// The name is empty if no strategy has been set.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    ///
+    /// assert_eq!(fn_value.get_gc().to_bytes(), b"");
+    ///
+    /// fn_value.set_gc("shadow-stack");
+    ///
+    /// assert_eq!(fn_value.get_gc().to_str(), Ok("shadow-stack"));
+    /// ```
     pub fn get_gc(&self) -> &CStr {
+        let gc = unsafe {
+            LLVMGetGC(self.as_value_ref())
+        };
+
+        // LLVM returns a null pointer rather than an empty string when no gc is set
+        if gc.is_null() {
+            return unsafe {
+                CStr::from_bytes_with_nul_unchecked(b"\0")
+            };
+        }
+
         unsafe {
-            CStr::from_ptr(LLVMGetGC(self.as_value_ref()))
+            CStr::from_ptr(gc)
         }
     }
 
+    /// Sets the name of the garbage collector strategy used by this `FunctionValue`,
+    /// such as `"shadow-stack"` or `"statepoint-example"`, so that LLVM emits stack
+    /// maps for it.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let fn_type = context.void_type().fn_type(&[], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    ///
+    /// fn_value.set_gc("shadow-stack");
+    ///
+    /// assert_eq!(fn_value.get_gc().to_str(), Ok("shadow-stack"));
+    /// ```
     pub fn set_gc(&self, gc: &str) {
         let c_string = CString::new(gc).expect("Conversion to CString failed unexpectedly");
 
@@ -329,6 +773,13 @@ impl AsValueRef for FunctionValue {
     }
 }
 
+// Allows FunctionValues to be passed directly to Builder::build_call
+impl From<FunctionValue> for Either<FunctionValue, PointerValue> {
+    fn from(value: FunctionValue) -> Self {
+        Either::Left(value)
+    }
+}
+
 impl fmt::Debug for FunctionValue {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let llvm_value = self.print_to_string();
@@ -3,6 +3,8 @@ use llvm_sys::execution_engine::{LLVMCreateGenericValueOfPointer, LLVMDisposeGen
 
 use types::{AsTypeRef, FloatType};
 
+/// A `GenericValue` holds an argument to or the return value of a function run by
+/// `ExecutionEngine::run_function`. It may hold an integer, a float, or a pointer.
 // SubTypes: GenericValue<IntValue, FloatValue, or PointerValue>
 #[derive(Debug)]
 pub struct GenericValue {
@@ -18,6 +20,7 @@ impl GenericValue {
         }
     }
 
+    /// Gets the bit width of an integer `GenericValue`.
     // SubType: GenericValue<IntValue> only
     pub fn int_width(&self) -> u32 {
         unsafe {
@@ -33,6 +36,8 @@ impl GenericValue {
         GenericValue::new(value)
     }
 
+    /// Gets the value of an integer `GenericValue`, sign extending it to 64 bits if `is_signed`
+    /// and zero extending it otherwise.
     // SubType: impl only for GenericValue<IntValue>
     pub fn as_int(&self, is_signed: bool) -> u64 {
         unsafe {
@@ -40,6 +45,7 @@ impl GenericValue {
         }
     }
 
+    /// Gets the value of a float `GenericValue`, which must have been created from `float_type`.
     // SubType: impl only for GenericValue<FloatValue>
     pub fn as_float(&self, float_type: &FloatType) -> f64 {
         unsafe {
@@ -1,10 +1,11 @@
 use llvm_sys::LLVMThreadLocalMode;
-use llvm_sys::core::{LLVMGetVisibility, LLVMSetVisibility, LLVMGetSection, LLVMSetSection, LLVMIsExternallyInitialized, LLVMSetExternallyInitialized, LLVMDeleteGlobal, LLVMIsGlobalConstant, LLVMSetGlobalConstant, LLVMGetPreviousGlobal, LLVMGetNextGlobal, LLVMHasUnnamedAddr, LLVMSetUnnamedAddr, LLVMIsThreadLocal, LLVMSetThreadLocal, LLVMGetThreadLocalMode, LLVMSetThreadLocalMode, LLVMGetInitializer, LLVMSetInitializer, LLVMIsDeclaration, LLVMGetDLLStorageClass, LLVMSetDLLStorageClass};
+use llvm_sys::core::{LLVMGetVisibility, LLVMSetVisibility, LLVMGetSection, LLVMSetSection, LLVMIsExternallyInitialized, LLVMSetExternallyInitialized, LLVMDeleteGlobal, LLVMIsGlobalConstant, LLVMSetGlobalConstant, LLVMGetPreviousGlobal, LLVMGetNextGlobal, LLVMHasUnnamedAddr, LLVMSetUnnamedAddr, LLVMIsThreadLocal, LLVMSetThreadLocal, LLVMGetThreadLocalMode, LLVMSetThreadLocalMode, LLVMGetInitializer, LLVMSetInitializer, LLVMIsDeclaration, LLVMGetDLLStorageClass, LLVMSetDLLStorageClass, LLVMGetLinkage, LLVMSetLinkage, LLVMGetAlignment, LLVMSetAlignment};
 use llvm_sys::prelude::LLVMValueRef;
 
 use std::ffi::{CString, CStr};
 
-use {GlobalVisibility, ThreadLocalMode, DLLStorageClass};
+use {GlobalVisibility, ThreadLocalMode, DLLStorageClass, UnnamedAddress};
+use module::Linkage;
 use values::traits::AsValueRef;
 use values::{BasicValueEnum, BasicValue, PointerValue, Value};
 
@@ -24,6 +25,22 @@ impl GlobalValue {
         }
     }
 
+    /// Gets the `GlobalValue` preceding this one in its `Module`, if any.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let i8_type = context.i8_type();
+    /// let global = module.add_global(i8_type, None, "my_global");
+    /// let global2 = module.add_global(i8_type, None, "my_global2");
+    ///
+    /// assert_eq!(global2.get_previous_global().unwrap(), global);
+    /// assert!(global.get_previous_global().is_none());
+    /// ```
     pub fn get_previous_global(&self) -> Option<GlobalValue> {
         let value = unsafe {
             LLVMGetPreviousGlobal(self.as_value_ref())
@@ -36,6 +53,22 @@ impl GlobalValue {
         Some(GlobalValue::new(value))
     }
 
+    /// Gets the `GlobalValue` following this one in its `Module`, if any.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let i8_type = context.i8_type();
+    /// let global = module.add_global(i8_type, None, "my_global");
+    /// let global2 = module.add_global(i8_type, None, "my_global2");
+    ///
+    /// assert_eq!(global.get_next_global().unwrap(), global2);
+    /// assert!(global2.get_next_global().is_none());
+    /// ```
     pub fn get_next_global(&self) -> Option<GlobalValue> {
         let value = unsafe {
             LLVMGetNextGlobal(self.as_value_ref())
@@ -48,6 +81,7 @@ impl GlobalValue {
         Some(GlobalValue::new(value))
     }
 
+    /// Gets the `DLLStorageClass` of this `GlobalValue`.
     pub fn get_dll_storage_class(&self) -> DLLStorageClass {
         let dll_storage_class = unsafe {
             LLVMGetDLLStorageClass(self.as_value_ref())
@@ -56,12 +90,14 @@ impl GlobalValue {
         DLLStorageClass::new(dll_storage_class)
     }
 
+    /// Sets the `DLLStorageClass` of this `GlobalValue`, independently of its `Linkage`.
     pub fn set_dll_storage_class(&self, dll_storage_class: DLLStorageClass) {
         unsafe {
             LLVMSetDLLStorageClass(self.as_value_ref(), dll_storage_class.as_llvm_class())
         }
     }
 
+    /// Gets the initializer of this `GlobalValue`, or `None` if it is only a declaration.
     pub fn get_initializer(&self) -> Option<BasicValueEnum> {
         let value = unsafe {
             LLVMGetInitializer(self.as_value_ref())
@@ -74,6 +110,27 @@ impl GlobalValue {
         Some(BasicValueEnum::new(value))
     }
 
+    /// Sets the initializer of this `GlobalValue`, which turns it into a definition.
+    /// The initializer's type must match the global's type. Mismatched types are undefined
+    /// behavior, which may only be caught once the `Module` is verified.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let i32_type = context.i32_type();
+    /// let i32_42 = i32_type.const_int(42, false);
+    /// let global = module.add_global(i32_type, None, "my_global");
+    ///
+    /// global.set_initializer(&i32_42);
+    /// global.set_constant(true);
+    ///
+    /// assert_eq!(global.get_initializer().unwrap().into_int_value(), i32_42);
+    /// assert!(global.is_constant());
+    /// ```
     // SubType: This input type should be tied to the BasicType
     pub fn set_initializer(&self, value: &BasicValue) {
         unsafe {
@@ -81,19 +138,22 @@ impl GlobalValue {
         }
     }
 
+    /// Determines whether or not this `GlobalValue` is thread local.
     pub fn is_thread_local(&self) -> bool {
         unsafe {
             LLVMIsThreadLocal(self.as_value_ref()) == 1
         }
     }
 
-    // TODOC: Setting this to true is the same as setting GeneralDynamicTLSModel
+    /// Sets whether or not this `GlobalValue` is thread local. Setting this to
+    /// true uses the `ThreadLocalMode::GeneralDynamicTLSModel` model.
     pub fn set_thread_local(&self, is_thread_local: bool) {
         unsafe {
             LLVMSetThreadLocal(self.as_value_ref(), is_thread_local as i32)
         }
     }
 
+    /// Gets the `ThreadLocalMode` of this `GlobalValue`, or `None` if it is not thread local.
     pub fn get_thread_local_mode(&self) -> Option<ThreadLocalMode> {
         let thread_local_mode = unsafe {
             LLVMGetThreadLocalMode(self.as_value_ref())
@@ -102,8 +162,24 @@ impl GlobalValue {
         ThreadLocalMode::new(thread_local_mode)
     }
 
-    // REVIEW: Does this have any bad behavior if it isn't thread local or just a noop?
-    // or should it call self.set_thread_local(true)?
+    /// Sets the `ThreadLocalMode` of this `GlobalValue`. Any mode will make the
+    /// global thread local, while `None` makes it no longer thread local.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::ThreadLocalMode;
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let global = module.add_global(context.i8_type(), None, "my_global");
+    ///
+    /// global.set_thread_local_mode(Some(ThreadLocalMode::LocalExecTLSModel));
+    ///
+    /// assert!(global.is_thread_local());
+    /// assert_eq!(global.get_thread_local_mode(), Some(ThreadLocalMode::LocalExecTLSModel));
+    /// ```
     pub fn set_thread_local_mode(&self, thread_local_mode: Option<ThreadLocalMode>) {
         let thread_local_mode = match thread_local_mode {
             Some(mode) => mode.as_llvm_mode(),
@@ -115,8 +191,26 @@ impl GlobalValue {
         }
     }
 
+    /// Determines whether or not this `GlobalValue` is only a declaration, which is
+    /// to say it has no initializer and is expected to be defined elsewhere.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let i8_type = context.i8_type();
+    /// let global = module.add_global(i8_type, None, "my_global");
+    ///
+    /// assert!(global.is_declaration());
+    ///
+    /// global.set_initializer(&i8_type.const_int(1, false));
+    ///
+    /// assert!(!global.is_declaration());
+    /// ```
     // SubType: This should be moved into the type. GlobalValue<Initialized/Uninitialized>
-    // TODO: FunctionValues as well?
     pub fn is_declaration(&self) -> bool {
         unsafe {
             LLVMIsDeclaration(self.as_value_ref()) == 1
@@ -135,12 +229,52 @@ impl GlobalValue {
         }
     }
 
+    /// Gets the `UnnamedAddress` of this `GlobalValue`. Prior to LLVM 7.0,
+    /// `UnnamedAddress::Local` cannot be detected and is reported as `UnnamedAddress::None`.
+    pub fn get_unnamed_address(&self) -> UnnamedAddress {
+        if self.has_unnamed_addr() {
+            UnnamedAddress::Global
+        } else {
+            UnnamedAddress::None
+        }
+    }
+
+    /// Sets the `UnnamedAddress` of this `GlobalValue`. Prior to LLVM 7.0, only a boolean
+    /// flag is available, so `UnnamedAddress::Local` conservatively falls back to
+    /// `UnnamedAddress::None`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::UnnamedAddress;
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let global = module.add_global(context.i8_type(), None, "my_global");
+    ///
+    /// global.set_unnamed_address(UnnamedAddress::Global);
+    ///
+    /// assert_eq!(global.get_unnamed_address(), UnnamedAddress::Global);
+    /// ```
+    pub fn set_unnamed_address(&self, address: UnnamedAddress) {
+        let has_unnamed_addr = match address {
+            UnnamedAddress::Global => true,
+            UnnamedAddress::None | UnnamedAddress::Local => false,
+        };
+
+        self.set_unnamed_addr(has_unnamed_addr)
+    }
+
+    /// Determines whether or not this `GlobalValue` is marked as constant.
     pub fn is_constant(&self) -> bool {
         unsafe {
             LLVMIsGlobalConstant(self.as_value_ref()) == 1
         }
     }
 
+    /// Sets whether or not this `GlobalValue` is constant, meaning it will never be
+    /// written to after initialization.
     pub fn set_constant(&self, is_constant: bool) {
         unsafe {
             LLVMSetGlobalConstant(self.as_value_ref(), is_constant as i32)
@@ -159,12 +293,14 @@ impl GlobalValue {
         }
     }
 
+    /// Sets the `GlobalVisibility` of this `GlobalValue`.
     pub fn set_visibility(&self, visibility: GlobalVisibility) {
         unsafe {
             LLVMSetVisibility(self.as_value_ref(), visibility.as_llvm_visibility())
         }
     }
 
+    /// Gets the `GlobalVisibility` of this `GlobalValue`.
     pub fn get_visibility(&self) -> GlobalVisibility {
         let visibility = unsafe {
             LLVMGetVisibility(self.as_value_ref())
@@ -173,12 +309,91 @@ impl GlobalValue {
         GlobalVisibility::new(visibility)
     }
 
-    pub fn get_section(&self) -> &CStr {
+    pub fn get_linkage(&self) -> Linkage {
+        let linkage = unsafe {
+            LLVMGetLinkage(self.as_value_ref())
+        };
+
+        Linkage::new(linkage)
+    }
+
+    pub fn set_linkage(&self, linkage: Linkage) {
+        unsafe {
+            LLVMSetLinkage(self.as_value_ref(), linkage.as_llvm_linkage())
+        }
+    }
+
+    /// Gets the alignment of this `GlobalValue` in bytes. `0` means
+    /// no explicit alignment was set and the ABI default is used.
+    pub fn get_alignment(&self) -> u32 {
         unsafe {
-            CStr::from_ptr(LLVMGetSection(self.as_value_ref()))
+            LLVMGetAlignment(self.as_value_ref())
         }
     }
 
+    /// Sets the alignment of this `GlobalValue` in bytes. The alignment must be
+    /// a power of two, or `0` to use the ABI default.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let global = module.add_global(context.i8_type(), None, "my_global");
+    ///
+    /// assert_eq!(global.get_alignment(), 0);
+    ///
+    /// global.set_alignment(16);
+    ///
+    /// assert_eq!(global.get_alignment(), 16);
+    /// ```
+    pub fn set_alignment(&self, alignment: u32) {
+        unsafe {
+            LLVMSetAlignment(self.as_value_ref(), alignment)
+        }
+    }
+
+    /// Gets the section this `GlobalValue` will be placed in, if one has been set.
+    pub fn get_section(&self) -> Option<&CStr> {
+        let ptr = unsafe {
+            LLVMGetSection(self.as_value_ref())
+        };
+
+        // LLVM 4.0+ returns null when no section is set, whereas older versions return ""
+        if ptr.is_null() {
+            return None;
+        }
+
+        let section = unsafe {
+            CStr::from_ptr(ptr)
+        };
+
+        if section.to_bytes().is_empty() {
+            return None;
+        }
+
+        Some(section)
+    }
+
+    /// Sets the section this `GlobalValue` will be placed in.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let global = module.add_global(context.i8_type(), None, "my_global");
+    ///
+    /// assert!(global.get_section().is_none());
+    ///
+    /// global.set_section(".mydata");
+    ///
+    /// assert_eq!(global.get_section().unwrap().to_str(), Ok(".mydata"));
+    /// ```
     pub fn set_section(&self, section: &str) {
         let c_string = CString::new(section).expect("Conversion to CString failed unexpectedly");
 
@@ -187,10 +402,35 @@ impl GlobalValue {
         }
     }
 
+    /// Removes this `GlobalValue` from its `Module` and deletes it. This is unsafe because
+    /// `GlobalValue`s are `Copy`, so any remaining copies will be left dangling. Additionally,
+    /// LLVM does not check for remaining uses of the global, so callers must replace all uses
+    /// (see `replace_all_uses_with`) beforehand or risk leaving dangling references in the IR.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let i8_type = context.i8_type();
+    /// let global = module.add_global(i8_type, None, "my_global");
+    ///
+    /// unsafe {
+    ///     global.delete();
+    /// }
+    ///
+    /// assert!(module.get_global("my_global").is_none());
+    /// ```
     pub unsafe fn delete(self) {
         LLVMDeleteGlobal(self.as_value_ref())
     }
 
+    pub fn replace_all_uses_with(&self, other: &GlobalValue) {
+        self.global_value.replace_all_uses_with(other.as_value_ref())
+    }
+
     pub fn as_pointer_value(&self) -> PointerValue {
         PointerValue::new(self.as_value_ref())
     }
@@ -1,10 +1,12 @@
-use llvm_sys::core::{LLVMGetInstructionOpcode, LLVMIsTailCall, LLVMGetPreviousInstruction, LLVMGetNextInstruction, LLVMGetInstructionParent, LLVMInstructionEraseFromParent, LLVMInstructionClone, LLVMSetVolatile, LLVMGetVolatile};
+use llvm_sys::core::{LLVMGetInstructionOpcode, LLVMIsTailCall, LLVMGetPreviousInstruction, LLVMGetNextInstruction, LLVMGetInstructionParent, LLVMInstructionEraseFromParent, LLVMInstructionClone, LLVMSetVolatile, LLVMGetVolatile, LLVMGetInstructionCallConv, LLVMSetInstructionCallConv, LLVMGetAlignment, LLVMSetAlignment, LLVMAddClause, LLVMSetCleanup};
+#[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+use llvm_sys::core::LLVMIsCleanup;
 use llvm_sys::LLVMOpcode;
 use llvm_sys::prelude::LLVMValueRef;
 
 use basic_block::BasicBlock;
 use values::traits::AsValueRef;
-use values::Value;
+use values::{BasicValue, MetadataValue, Value};
 
 // REVIEW: Split up into structs for SubTypes on InstructionValues?
 // REVIEW: This should maybe be split up into InstructionOpcode and ConstOpcode?
@@ -311,6 +313,84 @@ impl InstructionValue {
         }
     }
 
+    // SubTypes: Only apply to alloca, load, and store instructions
+    /// Gets the alignment in bytes of an alloca, load, or store instruction.
+    /// `0` means no explicit alignment was set and the ABI default is used.
+    pub fn get_alignment(&self) -> u32 {
+        unsafe {
+            LLVMGetAlignment(self.as_value_ref())
+        }
+    }
+
+    // SubTypes: Only apply to alloca, load, and store instructions
+    /// Sets the alignment in bytes of an alloca, load, or store instruction.
+    /// The alignment must be a power of two, or `0` to use the ABI default.
+    pub fn set_alignment(&self, alignment: u32) {
+        unsafe {
+            LLVMSetAlignment(self.as_value_ref(), alignment)
+        }
+    }
+
+    // SubTypes: Only apply to call and invoke instructions
+    /// Gets the calling convention of a call or invoke instruction as its raw LLVM id.
+    pub fn get_call_conventions(&self) -> u32 {
+        unsafe {
+            LLVMGetInstructionCallConv(self.as_value_ref())
+        }
+    }
+
+    // SubTypes: Only apply to call and invoke instructions
+    /// Sets the calling convention of a call or invoke instruction from its raw LLVM id.
+    /// This should match the convention of the function being called.
+    pub fn set_call_conventions(&self, call_conventions: u32) {
+        unsafe {
+            LLVMSetInstructionCallConv(self.as_value_ref(), call_conventions)
+        }
+    }
+
+    /// Determines whether or not this instruction has any metadata attached.
+    pub fn has_metadata(&self) -> bool {
+        self.instruction_value.has_metadata()
+    }
+
+    /// Gets the metadata node attached to this instruction under a kind id, if any.
+    /// Kind ids can be obtained from `Context::get_kind_id`.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    /// let entry = fn_value.append_basic_block("entry");
+    ///
+    /// builder.position_at_end(&entry);
+    ///
+    /// let param = fn_value.get_first_param().unwrap().into_int_value();
+    /// let add = builder.build_int_add(param, param, "add");
+    /// let add_instruction = add.as_instruction().unwrap();
+    /// let kind_id = context.get_kind_id("my_kind");
+    /// let md_node = context.metadata_node(&[i32_type.const_int(1, false).into()]);
+    ///
+    /// add_instruction.set_metadata(&md_node, kind_id);
+    ///
+    /// assert_eq!(add_instruction.get_metadata(kind_id).unwrap(), md_node);
+    /// ```
+    pub fn get_metadata(&self, kind_id: u32) -> Option<MetadataValue> {
+        self.instruction_value.get_metadata(kind_id)
+    }
+
+    /// Attaches a metadata node to this instruction under a kind id, replacing
+    /// any metadata which was previously attached under that kind id.
+    pub fn set_metadata(&self, metadata: &MetadataValue, kind_id: u32) {
+        self.instruction_value.set_metadata(metadata, kind_id)
+    }
+
     pub fn replace_all_uses_with(&self, other: &InstructionValue) {
         self.instruction_value.replace_all_uses_with(other.as_value_ref())
     }
@@ -330,6 +410,35 @@ impl InstructionValue {
             LLVMSetVolatile(self.as_value_ref(), volatile as i32)
         }
     }
+
+    // SubTypes: Only apply to landingpad instructions
+    /// Adds a clause to a landingpad instruction. A constant global of the exception's
+    /// type info catches matching exceptions, while a constant array of type infos acts
+    /// as a filter.
+    pub fn add_clause<V: BasicValue>(&self, clause: V) {
+        unsafe {
+            LLVMAddClause(self.as_value_ref(), clause.as_value_ref())
+        }
+    }
+
+    // SubTypes: Only apply to landingpad instructions
+    /// Returns whether or not a landingpad instruction is a cleanup, which is entered
+    /// regardless of whether any of its clauses match.
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+    pub fn is_cleanup(&self) -> bool {
+        unsafe {
+            LLVMIsCleanup(self.as_value_ref()) == 1
+        }
+    }
+
+    // SubTypes: Only apply to landingpad instructions
+    /// Sets whether or not a landingpad instruction is a cleanup, which is entered
+    /// regardless of whether any of its clauses match.
+    pub fn set_cleanup(&self, cleanup: bool) {
+        unsafe {
+            LLVMSetCleanup(self.as_value_ref(), cleanup as i32)
+        }
+    }
 }
 
 impl Clone for InstructionValue {
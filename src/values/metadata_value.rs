@@ -8,7 +8,6 @@ use values::{BasicValue, BasicMetadataValueEnum, Value};
 use std::ffi::{CString, CStr};
 use std::fmt;
 use std::mem::forget;
-use std::slice::from_raw_parts;
 
 // TODOC: Varies by version
 #[cfg(feature = "llvm3-6")]
@@ -79,6 +78,18 @@ impl MetadataValue {
         MetadataValue::new(metadata_value)
     }
 
+    /// Gets the content of a metadata string, or `None` if this is a node.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let md_string = context.metadata_string("my_string");
+    ///
+    /// assert_eq!(md_string.get_string_value().unwrap().to_str(), Ok("my_string"));
+    /// ```
     pub fn get_string_value(&self) -> Option<&CStr> {
         if self.is_node() {
             return None;
@@ -92,6 +103,7 @@ impl MetadataValue {
         Some(c_str)
     }
 
+    /// Gets the number of values in a metadata node, or `0` if this is a string.
     // SubTypes: Node only one day
     pub fn get_node_size(&self) -> u32 {
         if self.is_string() {
@@ -103,6 +115,23 @@ impl MetadataValue {
         }
     }
 
+    /// Gets the values of a metadata node, or an empty `Vec` if this is a string.
+    /// Nested metadata strings and nodes are returned as `MetadataValue`s.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let i32_type = context.i32_type();
+    /// let i32_one = i32_type.const_int(1, false);
+    /// let md_node = context.metadata_node(&[i32_one.into()]);
+    /// let node_values = md_node.get_node_values();
+    ///
+    /// assert_eq!(node_values.len(), 1);
+    /// assert_eq!(node_values[0].into_int_value(), i32_one);
+    /// ```
     // SubTypes: Node only one day
     // REVIEW: BasicMetadataValueEnum only if you can put metadata in metadata...
     pub fn get_node_values(&self) -> Vec<BasicMetadataValueEnum> {
@@ -116,13 +145,13 @@ impl MetadataValue {
 
         forget(raw_vec);
 
-        let slice = unsafe {
+        let raw_vec = unsafe {
             LLVMGetMDNodeOperands(self.as_value_ref(), ptr);
 
-            from_raw_parts(ptr, count as usize)
+            Vec::from_raw_parts(ptr, count as usize, count as usize)
         };
 
-        slice.iter().map(|val| BasicMetadataValueEnum::new(*val)).collect()
+        raw_vec.iter().map(|val| BasicMetadataValueEnum::new(*val)).collect()
     }
 
     // What is this even useful for
@@ -23,6 +23,41 @@ impl PhiValue {
         }
     }
 
+    /// Adds incoming values to this phi, each paired with the predecessor `BasicBlock`
+    /// control flow arrives from when the phi takes that value. A phi must have exactly
+    /// one entry per predecessor of its block for the function to verify.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let fn_type = i32_type.fn_type(&[context.bool_type().into()], false);
+    /// let fn_value = module.add_function("choose", &fn_type, None);
+    /// let entry = fn_value.append_basic_block("entry");
+    /// let then_block = fn_value.append_basic_block("then");
+    /// let merge = fn_value.append_basic_block("merge");
+    /// let cond = fn_value.get_first_param().unwrap().into_int_value();
+    ///
+    /// builder.position_at_end(&entry);
+    /// builder.build_conditional_branch(&cond, &then_block, &merge);
+    /// builder.position_at_end(&then_block);
+    /// builder.build_unconditional_branch(&merge);
+    /// builder.position_at_end(&merge);
+    ///
+    /// let phi = builder.build_phi(i32_type, "chosen");
+    ///
+    /// phi.add_incoming(&[
+    ///     (&i32_type.const_int(1, false), &entry),
+    ///     (&i32_type.const_int(2, false), &then_block),
+    /// ]);
+    ///
+    /// builder.build_return(Some(&phi.as_basic_value()));
+    /// ```
     pub fn add_incoming(&self, incoming: &[(&BasicValue, &BasicBlock)]) {
         let (mut values, mut basic_blocks): (Vec<LLVMValueRef>, Vec<LLVMBasicBlockRef>) = {
             incoming.iter()
@@ -35,12 +70,15 @@ impl PhiValue {
         }
     }
 
+    /// Counts the number of incoming values this phi has.
     pub fn count_incoming(&self) -> u32 {
         unsafe {
             LLVMCountIncoming(self.as_value_ref())
         }
     }
 
+    /// Gets the incoming value at `index` along with the predecessor `BasicBlock` it
+    /// comes from, or `None` if `index` is out of bounds.
     pub fn get_incoming(&self, index: u32) -> Option<(BasicValueEnum, BasicBlock)> {
         if index >= self.count_incoming() {
             return None;
@@ -1,11 +1,12 @@
-use llvm_sys::core::{LLVMConstGEP, LLVMConstInBoundsGEP, LLVMConstPtrToInt, LLVMConstPointerCast, LLVMConstAddrSpaceCast};
+use llvm_sys::core::{LLVMConstGEP, LLVMConstInBoundsGEP, LLVMConstPtrToInt, LLVMConstPointerCast, LLVMConstAddrSpaceCast, LLVMConstBitCast};
 use llvm_sys::prelude::LLVMValueRef;
+use either::Either;
 
 use std::ffi::CStr;
 
 use support::LLVMString;
 use types::{AsTypeRef, IntType, PointerType};
-use values::{AsValueRef, InstructionValue, IntValue, Value, MetadataValue};
+use values::{AsValueRef, FunctionValue, InstructionValue, IntValue, Value, MetadataValue};
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub struct PointerValue {
@@ -66,7 +67,30 @@ impl PointerValue {
     }
 
     // REVIEW: Should this be on array value too?
+    /// Creates a constant `PointerValue` to an element within the pointee of this
+    /// constant pointer, such as an element of a global array. This is useful for
+    /// building global initializers, ie vtables or tables of pointers.
+    ///
     /// GEP is very likely to segfault if indexes are used incorrectly, and is therefore an unsafe function. Maybe we can change this in the future.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::AddressSpace;
+    /// use inkwell::context::Context;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let i32_type = context.i32_type();
+    /// let array_type = i32_type.array_type(4);
+    /// let global = module.add_global(array_type, None, "my_array");
+    /// let indexes = [i32_type.const_int(0, false), i32_type.const_int(2, false)];
+    /// let element_ptr = unsafe {
+    ///     global.as_pointer_value().const_gep(&indexes)
+    /// };
+    ///
+    /// assert_eq!(element_ptr.get_type(), i32_type.ptr_type(AddressSpace::Generic));
+    /// ```
     pub unsafe fn const_gep(&self, ordered_indexes: &[IntValue]) -> PointerValue {
         let mut index_values: Vec<LLVMValueRef> = ordered_indexes.iter()
                                                                  .map(|val| val.as_value_ref())
@@ -78,6 +102,9 @@ impl PointerValue {
         PointerValue::new(value)
     }
 
+    /// Like `const_gep`, but the resulting pointer is poison if it lies out of bounds
+    /// of the pointee.
+    ///
     /// GEP is very likely to segfault if indexes are used incorrectly, and is therefore an unsafe function. Maybe we can change this in the future.
     pub unsafe fn const_in_bounds_gep(&self, ordered_indexes: &[IntValue]) -> PointerValue {
         let mut index_values: Vec<LLVMValueRef> = ordered_indexes.iter()
@@ -90,6 +117,7 @@ impl PointerValue {
         PointerValue::new(value)
     }
 
+    /// Converts this constant pointer into a constant integer of the given `IntType`.
     pub fn const_to_int(&self, int_type: &IntType) -> IntValue {
         let value = unsafe {
             LLVMConstPtrToInt(self.as_value_ref(), int_type.as_type_ref())
@@ -98,6 +126,8 @@ impl PointerValue {
         IntValue::new(value)
     }
 
+    /// Casts this constant pointer to a constant pointer of another `PointerType`, using
+    /// a bitcast when both are in the same address space and an address space cast otherwise.
     pub fn const_cast(&self, ptr_type: &PointerType) -> PointerValue {
         let value = unsafe {
             LLVMConstPointerCast(self.as_value_ref(), ptr_type.as_type_ref())
@@ -106,6 +136,17 @@ impl PointerValue {
         PointerValue::new(value)
     }
 
+    /// Bitcasts this constant pointer to a constant pointer of another `PointerType` in the
+    /// same address space.
+    pub fn const_bit_cast(&self, ptr_type: &PointerType) -> PointerValue {
+        let value = unsafe {
+            LLVMConstBitCast(self.as_value_ref(), ptr_type.as_type_ref())
+        };
+
+        PointerValue::new(value)
+    }
+
+    /// Casts this constant pointer to a constant pointer of a `PointerType` in another address space.
     pub fn const_address_space_cast(&self, ptr_type: &PointerType) -> PointerValue {
         let value = unsafe {
             LLVMConstAddrSpaceCast(self.as_value_ref(), ptr_type.as_type_ref())
@@ -124,3 +165,10 @@ impl AsValueRef for PointerValue {
         self.ptr_value.value
     }
 }
+
+// Allows function pointers to be passed directly to Builder::build_call
+impl From<PointerValue> for Either<FunctionValue, PointerValue> {
+    fn from(value: PointerValue) -> Self {
+        Either::Right(value)
+    }
+}
@@ -1,8 +1,9 @@
+use llvm_sys::core::LLVMGetFirstUse;
 use llvm_sys::prelude::LLVMValueRef;
 
 use std::fmt::Debug;
 
-use values::{ArrayValue, AggregateValueEnum, GlobalValue, StructValue, BasicValueEnum, AnyValueEnum, IntValue, FloatValue, PointerValue, PhiValue, VectorValue, FunctionValue, InstructionValue};
+use values::{ArrayValue, AggregateValueEnum, BasicValueUse, GlobalValue, Value, StructValue, BasicValueEnum, AnyValueEnum, IntValue, FloatValue, PointerValue, PhiValue, VectorValue, FunctionValue, InstructionValue};
 use types::{IntMathType, FloatMathType, PointerMathType, IntType, FloatType, PointerType, VectorType};
 
 // This is an ugly privacy hack so that Type can stay private to this module
@@ -50,6 +51,58 @@ pub trait BasicValue: AnyValue {
     fn as_basic_value_enum(&self) -> BasicValueEnum {
         BasicValueEnum::new(self.as_value_ref())
     }
+
+    /// Replaces all uses of this value with `new_value`, leaving this value unused.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_value` does not have the same type as this value. LLVM would
+    /// otherwise trip an assertion, or silently produce IR the verifier rejects.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use inkwell::context::Context;
+    /// use inkwell::values::BasicValue;
+    ///
+    /// let context = Context::create();
+    /// let module = context.create_module("my_mod");
+    /// let builder = context.create_builder();
+    /// let i32_type = context.i32_type();
+    /// let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    /// let fn_value = module.add_function("my_fn", &fn_type, None);
+    /// let entry = fn_value.append_basic_block("entry");
+    ///
+    /// builder.position_at_end(&entry);
+    ///
+    /// let param = fn_value.get_first_param().unwrap();
+    ///
+    /// builder.build_return(Some(&param));
+    ///
+    /// param.replace_all_uses_with(&i32_type.const_int(42, false));
+    /// ```
+    fn replace_all_uses_with(&self, new_value: &BasicValue) {
+        let value = Value::new(self.as_value_ref());
+        let new_value = new_value.as_value_ref();
+
+        assert_eq!(value.get_type(), Value::new(new_value).get_type(), "replace_all_uses_with requires values of the same type");
+
+        value.replace_all_uses_with(new_value)
+    }
+
+    /// Gets the first use of this value, from which all of its uses can be walked
+    /// with `BasicValueUse::get_next_use`. Returns `None` if the value is unused.
+    fn get_first_use(&self) -> Option<BasicValueUse> {
+        let use_ = unsafe {
+            LLVMGetFirstUse(self.as_value_ref())
+        };
+
+        if use_.is_null() {
+            return None;
+        }
+
+        Some(BasicValueUse::new(use_))
+    }
 }
 
 /// Represents a value which is permitted in integer math operations
@@ -0,0 +1,97 @@
+extern crate inkwell;
+
+use self::inkwell::attributes::{Attribute, AttributeLoc};
+use self::inkwell::context::Context;
+
+#[test]
+fn test_enum_attribute() {
+    let context = Context::create();
+    let enum_attribute = context.create_enum_attribute(0, 10);
+
+    assert!(enum_attribute.is_enum());
+    assert!(!enum_attribute.is_string());
+    assert_eq!(enum_attribute.get_enum_kind_id(), 0);
+    assert_eq!(enum_attribute.get_enum_value(), 10);
+}
+
+#[test]
+fn test_string_attribute() {
+    let context = Context::create();
+    let string_attribute = context.create_string_attribute("my_key", "my_val");
+
+    assert!(string_attribute.is_string());
+    assert!(!string_attribute.is_enum());
+    assert_eq!(string_attribute.get_string_kind_id(), b"my_key");
+    assert_eq!(string_attribute.get_string_value(), b"my_val");
+}
+
+#[test]
+#[should_panic]
+fn test_string_attribute_enum_kind_id() {
+    let context = Context::create();
+    let string_attribute = context.create_string_attribute("my_key", "my_val");
+
+    string_attribute.get_enum_kind_id();
+}
+
+#[test]
+fn test_function_attributes() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let fn_value = module.add_function("my_fn", &fn_type, None);
+    let fn_attribute = context.create_string_attribute("my_key", "my_val");
+    let param_attribute = context.create_string_attribute("my_param_key", "");
+
+    assert_eq!(fn_value.count_attributes(AttributeLoc::Function), 0);
+    assert_eq!(fn_value.count_attributes(AttributeLoc::Param(0)), 0);
+    assert!(fn_value.get_string_attribute(AttributeLoc::Function, "my_key").is_none());
+
+    fn_value.add_attribute(AttributeLoc::Function, fn_attribute);
+    fn_value.add_attribute(AttributeLoc::Param(0), param_attribute);
+
+    assert_eq!(fn_value.count_attributes(AttributeLoc::Function), 1);
+    assert_eq!(fn_value.count_attributes(AttributeLoc::Param(0)), 1);
+    assert_eq!(fn_value.count_attributes(AttributeLoc::Return), 0);
+    assert_eq!(fn_value.get_string_attribute(AttributeLoc::Function, "my_key"), Some(fn_attribute));
+    assert_eq!(fn_value.get_string_attribute(AttributeLoc::Param(0), "my_param_key"), Some(param_attribute));
+
+    let ir = module.print_to_string();
+
+    assert!(ir.to_string_lossy().contains("\"my_key\"=\"my_val\""));
+
+    fn_value.remove_string_attribute(AttributeLoc::Function, "my_key");
+
+    assert_eq!(fn_value.count_attributes(AttributeLoc::Function), 0);
+    assert!(fn_value.get_string_attribute(AttributeLoc::Function, "my_key").is_none());
+}
+
+#[test]
+fn test_named_enum_kind_ids() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+    let fn_value = module.add_function("my_fn", &fn_type, None);
+    let nounwind_kind_id = Attribute::get_named_enum_kind_id("nounwind");
+    let last_kind_id = Attribute::get_last_enum_kind_id();
+
+    assert_eq!(Attribute::get_named_enum_kind_id("foobar"), 0);
+    assert_ne!(nounwind_kind_id, 0);
+    assert!(nounwind_kind_id <= last_kind_id);
+    assert_ne!(Attribute::get_named_enum_kind_id("noinline"), nounwind_kind_id);
+
+    let nounwind_attribute = context.create_enum_attribute(nounwind_kind_id, 0);
+
+    assert_eq!(nounwind_attribute.get_enum_kind_id(), nounwind_kind_id);
+
+    fn_value.add_attribute(AttributeLoc::Function, nounwind_attribute);
+
+    assert_eq!(fn_value.get_enum_attribute(AttributeLoc::Function, nounwind_kind_id), Some(nounwind_attribute));
+    assert!(module.print_to_string().to_string_lossy().contains("nounwind"));
+
+    fn_value.remove_enum_attribute(AttributeLoc::Function, nounwind_kind_id);
+
+    assert!(fn_value.get_enum_attribute(AttributeLoc::Function, nounwind_kind_id).is_none());
+}
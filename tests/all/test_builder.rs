@@ -6,6 +6,7 @@ use self::inkwell::builder::Builder;
 use self::inkwell::targets::{InitializationConfig, Target};
 use self::inkwell::execution_engine::Symbol;
 use self::inkwell::types::BasicType;
+use self::inkwell::values::InstructionOpcode;
 
 use std::ffi::CString;
 use std::ptr::null;
@@ -38,6 +39,52 @@ fn test_build_call() {
     builder.build_return(Some(&pi2));
 }
 
+#[test]
+fn test_inline_asm() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let fn_value = module.add_function("my_fn", &fn_type, None);
+    let entry = fn_value.append_basic_block("entry");
+    let param = fn_value.get_first_param().unwrap();
+    let nop_type = context.void_type().fn_type(&[], false);
+    let nop = context.create_inline_asm(nop_type, "nop", "", true, false);
+    let mov = context.create_inline_asm(fn_type, "mov $1, $0", "=r,r", false, false);
+
+    assert_eq!(mov.get_type().get_element_type().into_function_type(), fn_type);
+
+    builder.position_at_end(&entry);
+
+    assert!(builder.build_call(nop, &[], "nop", false).right().is_some());
+
+    let moved = builder.build_call(mov, &[param], "moved", false).left().unwrap();
+
+    builder.build_return(Some(&moved));
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("call void asm sideeffect \"nop\", \"\"()"));
+    assert!(ir.contains("%moved = call i32 asm \"mov $1, $0\", \"=r,r\"(i32 %0)"));
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+#[should_panic]
+fn test_build_call_non_function_pointer() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let builder = context.create_builder();
+    let fn_type = context.void_type().fn_type(&[], false);
+    let fn_value = module.add_function("my_fn", &fn_type, None);
+    let entry = fn_value.append_basic_block("entry");
+    let global = module.add_global(context.i32_type(), None, "my_global");
+
+    builder.position_at_end(&entry);
+    builder.build_call(global.as_pointer_value(), &[], "call", false);
+}
+
 #[test]
 fn test_null_checked_ptr_ops() {
     Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
@@ -290,6 +337,44 @@ fn test_switch() {
     }
 }
 
+#[test]
+fn test_switch_verifies() {
+    let context = Context::create();
+    let builder = context.create_builder();
+    let module = context.create_module("my_mod");
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let fn_value = module.add_function("classify", &fn_type, None);
+    let entry_bb = fn_value.append_basic_block("entry");
+    let zero_bb = fn_value.append_basic_block("zero");
+    let one_bb = fn_value.append_basic_block("one");
+    let two_bb = fn_value.append_basic_block("two");
+    let default_bb = fn_value.append_basic_block("default");
+    let value = fn_value.get_first_param().unwrap().into_int_value();
+
+    builder.position_at_end(&entry_bb);
+
+    let switch = builder.build_switch(&value, &default_bb, &[
+        (&i32_type.const_int(0, false), &zero_bb),
+        (&i32_type.const_int(1, false), &one_bb),
+        (&i32_type.const_int(2, false), &two_bb),
+    ]);
+
+    assert_eq!(switch.get_opcode(), InstructionOpcode::Switch);
+
+    for (basic_block, ret) in [&zero_bb, &one_bb, &two_bb, &default_bb].iter().zip(&[10, 20, 30, 0]) {
+        builder.position_at_end(basic_block);
+        builder.build_return(Some(&i32_type.const_int(*ret, false)));
+    }
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("switch i32 %0, label %default ["));
+    assert!(ir.contains("i32 2, label %two"));
+}
+
 #[test]
 fn test_bit_shifts() {
     Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
@@ -410,6 +495,309 @@ fn test_unconditional_branch() {
     builder.build_unreachable();
 }
 
+#[test]
+fn test_builder_positioning() {
+    let context = Context::create();
+    let builder = context.create_builder();
+    let module = context.create_module("my_mod");
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+    let fn_value = module.add_function("my_fn", &fn_type, None);
+    let entry_bb = fn_value.append_basic_block("entry");
+    let other_bb = fn_value.append_basic_block("other");
+
+    assert!(builder.get_insert_block().is_none());
+
+    builder.position_at_end(&entry_bb);
+
+    assert_eq!(builder.get_insert_block().unwrap(), entry_bb);
+
+    let branch = builder.build_unconditional_branch(&other_bb);
+
+    builder.position_at_end(&other_bb);
+
+    assert_eq!(builder.get_insert_block().unwrap(), other_bb);
+
+    let ret = builder.build_return(None);
+
+    builder.position_before(&branch);
+
+    assert_eq!(builder.get_insert_block().unwrap(), entry_bb);
+
+    let unreachable = builder.build_unreachable();
+
+    assert_eq!(entry_bb.get_first_instruction().unwrap(), unreachable);
+    assert_eq!(entry_bb.get_last_instruction().unwrap(), branch);
+    assert_eq!(other_bb.get_last_instruction().unwrap(), ret);
+
+    builder.clear_insertion_position();
+
+    assert!(builder.get_insert_block().is_none());
+}
+
+#[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+#[test]
+fn test_atomic_load_store() {
+    use self::inkwell::AtomicOrdering;
+
+    let context = Context::create();
+    let builder = context.create_builder();
+    let module = context.create_module("my_mod");
+    let i32_type = context.i32_type();
+    let i32_ptr_type = i32_type.ptr_type(AddressSpace::Generic);
+    let fn_type = i32_type.fn_type(&[i32_ptr_type.into()], false);
+    let fn_value = module.add_function("my_fn", &fn_type, None);
+    let entry_bb = fn_value.append_basic_block("entry");
+    let ptr = fn_value.get_first_param().unwrap().into_pointer_value();
+    let seven = i32_type.const_int(7, false);
+
+    builder.position_at_end(&entry_bb);
+
+    assert!(builder.build_store_atomic(ptr, seven, AtomicOrdering::Acquire).is_err());
+    assert!(builder.build_store_atomic(ptr, seven, AtomicOrdering::AcquireRelease).is_err());
+    assert!(builder.build_load_atomic(ptr, AtomicOrdering::Release, "bad").is_err());
+    assert!(builder.build_load_atomic(ptr, AtomicOrdering::AcquireRelease, "bad").is_err());
+    assert!(entry_bb.get_first_instruction().is_none());
+
+    let store = builder.build_store_atomic(ptr, seven, AtomicOrdering::SequentiallyConsistent).unwrap();
+
+    store.set_alignment(4);
+
+    let value = builder.build_load_atomic(ptr, AtomicOrdering::Acquire, "value").unwrap();
+
+    value.as_instruction().unwrap().set_alignment(4);
+
+    builder.build_return(Some(&value));
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("store atomic i32 7, i32* %0 seq_cst, align 4"));
+    assert!(ir.contains("%value = load atomic i32, i32* %0 acquire, align 4"));
+}
+
+#[test]
+fn test_atomicrmw() {
+    use self::inkwell::{AtomicOrdering, AtomicRMWBinOp};
+
+    let context = Context::create();
+    let builder = context.create_builder();
+    let module = context.create_module("my_mod");
+    let i32_type = context.i32_type();
+    let i64_type = context.i64_type();
+    let bool_type = context.bool_type();
+    let counter = module.add_global(i32_type, None, "counter");
+    let flag = module.add_global(bool_type, None, "flag");
+    let fn_type = i32_type.fn_type(&[], false);
+    let fn_value = module.add_function("increment", &fn_type, None);
+    let entry_bb = fn_value.append_basic_block("entry");
+    let counter_ptr = counter.as_pointer_value();
+
+    builder.position_at_end(&entry_bb);
+
+    let i64_one = i64_type.const_int(1, false);
+    let bool_true = bool_type.const_int(1, false);
+    let one = i32_type.const_int(1, false);
+
+    assert!(builder.build_atomicrmw(AtomicRMWBinOp::Add, counter_ptr, i64_one, AtomicOrdering::SequentiallyConsistent).is_err());
+    assert!(builder.build_atomicrmw(AtomicRMWBinOp::Xchg, flag.as_pointer_value(), bool_true, AtomicOrdering::SequentiallyConsistent).is_err());
+    assert!(builder.build_atomicrmw(AtomicRMWBinOp::Add, counter_ptr, one, AtomicOrdering::Unordered).is_err());
+    assert!(entry_bb.get_first_instruction().is_none());
+
+    let old = builder.build_atomicrmw(AtomicRMWBinOp::Add, counter_ptr, one, AtomicOrdering::SequentiallyConsistent).unwrap();
+
+    assert_eq!(old.get_type(), i32_type);
+
+    builder.build_atomicrmw(AtomicRMWBinOp::UMax, counter_ptr, one, AtomicOrdering::Monotonic).unwrap();
+    builder.build_return(Some(&old));
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("atomicrmw add i32* @counter, i32 1 seq_cst"));
+    assert!(ir.contains("atomicrmw umax i32* @counter, i32 1 monotonic"));
+}
+
+#[test]
+fn test_cmpxchg() {
+    use self::inkwell::AtomicOrdering;
+
+    let context = Context::create();
+    let builder = context.create_builder();
+    let module = context.create_module("my_mod");
+    let i32_type = context.i32_type();
+    let i64_type = context.i64_type();
+    let bool_type = context.bool_type();
+    let lock = module.add_global(i32_type, None, "lock");
+    let fn_type = context.void_type().fn_type(&[], false);
+    let fn_value = module.add_function("spin_lock", &fn_type, None);
+    let entry_bb = fn_value.append_basic_block("entry");
+    let loop_bb = fn_value.append_basic_block("loop");
+    let end_bb = fn_value.append_basic_block("end");
+    let lock_ptr = lock.as_pointer_value();
+    let unlocked = i32_type.const_int(0, false);
+    let locked = i32_type.const_int(1, false);
+    let i64_zero = i64_type.const_int(0, false);
+    let i64_one = i64_type.const_int(1, false);
+
+    builder.position_at_end(&entry_bb);
+    builder.build_unconditional_branch(&loop_bb);
+    builder.position_at_end(&loop_bb);
+
+    assert!(builder.build_cmpxchg(lock_ptr, i64_zero, i64_one, AtomicOrdering::AcquireRelease, AtomicOrdering::Monotonic).is_err());
+    assert!(builder.build_cmpxchg(lock_ptr, unlocked, locked, AtomicOrdering::Unordered, AtomicOrdering::Unordered).is_err());
+    assert!(builder.build_cmpxchg(lock_ptr, unlocked, locked, AtomicOrdering::AcquireRelease, AtomicOrdering::Release).is_err());
+    assert!(builder.build_cmpxchg(lock_ptr, unlocked, locked, AtomicOrdering::AcquireRelease, AtomicOrdering::AcquireRelease).is_err());
+    assert!(builder.build_cmpxchg(lock_ptr, unlocked, locked, AtomicOrdering::Monotonic, AtomicOrdering::SequentiallyConsistent).is_err());
+    assert!(builder.build_cmpxchg(lock_ptr, unlocked, locked, AtomicOrdering::Acquire, AtomicOrdering::SequentiallyConsistent).is_err());
+    assert!(loop_bb.get_first_instruction().is_none());
+
+    let result = builder.build_cmpxchg(lock_ptr, unlocked, locked, AtomicOrdering::AcquireRelease, AtomicOrdering::Monotonic).unwrap();
+    let result_type = result.get_type();
+
+    assert_eq!(result_type.count_fields(), 2);
+    assert_eq!(result_type.get_field_types()[0].into_int_type(), i32_type);
+    assert_eq!(result_type.get_field_types()[1].into_int_type(), bool_type);
+
+    let success = builder.build_extract_value(result, 1, "success").unwrap().into_int_value();
+
+    builder.build_conditional_branch(&success, &end_bb, &loop_bb);
+    builder.position_at_end(&end_bb);
+    builder.build_return(None);
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("cmpxchg i32* @lock, i32 0, i32 1 acq_rel monotonic"));
+}
+
+#[test]
+fn test_fence() {
+    use self::inkwell::AtomicOrdering;
+
+    let context = Context::create();
+    let builder = context.create_builder();
+    let module = context.create_module("my_mod");
+    let fn_type = context.void_type().fn_type(&[], false);
+    let fn_value = module.add_function("my_fn", &fn_type, None);
+    let entry_bb = fn_value.append_basic_block("entry");
+
+    builder.position_at_end(&entry_bb);
+
+    let fence = builder.build_fence(AtomicOrdering::SequentiallyConsistent, false, "").unwrap();
+
+    builder.build_fence(AtomicOrdering::Acquire, true, "").unwrap();
+    builder.build_fence(AtomicOrdering::Release, false, "").unwrap();
+
+    assert!(builder.build_fence(AtomicOrdering::Unordered, false, "").is_err());
+    assert!(builder.build_fence(AtomicOrdering::Monotonic, false, "").is_err());
+
+    builder.build_return(None);
+
+    assert_eq!(fence.get_opcode(), InstructionOpcode::Fence);
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("fence seq_cst"));
+    #[cfg(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8", feature = "llvm3-9", feature = "llvm4-0"))]
+    assert!(ir.contains("fence singlethread acquire"));
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8", feature = "llvm3-9", feature = "llvm4-0")))]
+    assert!(ir.contains("fence syncscope(\"singlethread\") acquire"));
+    assert!(ir.contains("fence release"));
+}
+
+#[test]
+fn test_extract_and_insert_value() {
+    let context = Context::create();
+    let builder = context.create_builder();
+    let module = context.create_module("my_mod");
+    let i32_type = context.i32_type();
+    let i8_type = context.i8_type();
+    let struct_type = context.struct_type(&[i32_type.into(), i8_type.into()], false);
+    let fn_type = struct_type.fn_type(&[struct_type.into()], false);
+    let fn_value = module.add_function("my_fn", &fn_type, None);
+    let entry_bb = fn_value.append_basic_block("entry");
+    let struct_value = fn_value.get_first_param().unwrap().into_struct_value();
+
+    builder.position_at_end(&entry_bb);
+
+    assert!(builder.build_extract_value(struct_value, 2, "out_of_bounds").is_none());
+    assert!(builder.build_insert_value(struct_value, i32_type.const_int(1, false), 2, "out_of_bounds").is_none());
+    assert!(entry_bb.get_first_instruction().is_none());
+
+    let field = builder.build_extract_value(struct_value, 1, "field").unwrap().into_int_value();
+
+    assert_eq!(field.get_type(), i8_type);
+
+    let widened = builder.build_int_z_extend(field, i32_type, "widened");
+    let new_struct = builder.build_insert_value(struct_value, widened, 0, "new_struct").unwrap().into_struct_value();
+
+    assert_eq!(new_struct.get_type(), struct_type);
+
+    builder.build_return(Some(&new_struct));
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("%field = extractvalue { i32, i8 } %0, 1"));
+    assert!(ir.contains("%new_struct = insertvalue { i32, i8 } %0, i32 %widened, 0"));
+
+    let array_type = i32_type.array_type(2);
+    let array = builder.build_insert_value(array_type.get_undef(), i32_type.const_int(3, false), 1, "array").unwrap();
+
+    assert!(array.is_array_value());
+    assert!(builder.build_extract_value(array, 2, "out_of_bounds").is_none());
+    assert_eq!(builder.build_extract_value(array, 1, "elem").unwrap().into_int_value().get_type(), i32_type);
+}
+
+#[test]
+fn test_select() {
+    let context = Context::create();
+    let builder = context.create_builder();
+    let module = context.create_module("my_mod");
+    let bool_type = context.bool_type();
+    let i32_type = context.i32_type();
+    let bool_vec_type = bool_type.vec_type(4);
+    let i32_vec_type = i32_type.vec_type(4);
+    let fn_type = context.void_type().fn_type(&[
+        bool_type.into(), i32_type.into(), i32_type.into(),
+        bool_vec_type.into(), i32_vec_type.into(), i32_vec_type.into(),
+    ], false);
+    let fn_value = module.add_function("my_fn", &fn_type, None);
+    let entry_bb = fn_value.append_basic_block("entry");
+    let params = fn_value.get_params();
+    let cond = params[0].into_int_value();
+    let a = params[1].into_int_value();
+    let b = params[2].into_int_value();
+    let vec_cond = params[3].into_vector_value();
+    let vec_a = params[4].into_vector_value();
+    let vec_b = params[5].into_vector_value();
+
+    builder.position_at_end(&entry_bb);
+
+    let selected = builder.build_select(cond, a, b, "selected");
+
+    assert_eq!(selected.into_int_value().get_type(), i32_type);
+
+    let vec_selected = builder.build_select(vec_cond, vec_a, vec_b, "vec_selected");
+
+    assert_eq!(vec_selected.into_vector_value().get_type(), i32_vec_type);
+
+    builder.build_return(None);
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("%selected = select i1 %0, i32 %1, i32 %2"));
+    assert!(ir.contains("%vec_selected = select <4 x i1> %3, <4 x i32> %4, <4 x i32> %5"));
+}
+
 #[test]
 fn test_no_builder_double_free() {
     let context = Context::create();
@@ -577,3 +965,267 @@ fn test_vector_pointer_ops() {
     builder.build_return(Some(&is_null_vec));
     assert!(fn_value.verify(true));
 }
+
+#[test]
+fn test_vector_element_ops() {
+    use self::inkwell::types::VectorType;
+
+    let context = Context::create();
+    let module = context.create_module("test");
+    let i32_type = context.i32_type();
+    let i32_vec_type = i32_type.vec_type(4);
+    let fn_type = i32_vec_type.fn_type(&[i32_vec_type.into(), i32_vec_type.into(), i32_type.into()], false);
+    let fn_value = module.add_function("test_elements", &fn_type, None);
+    let entry = fn_value.append_basic_block("entry");
+    let builder = context.create_builder();
+    let left = fn_value.get_first_param().unwrap().into_vector_value();
+    let right = fn_value.get_nth_param(1).unwrap().into_vector_value();
+    let element = fn_value.get_last_param().unwrap().into_int_value();
+    let two = i32_type.const_int(2, false);
+
+    builder.position_at_end(&entry);
+
+    let inserted = builder.build_insert_element(left, element, two, "inserted");
+
+    assert_eq!(inserted.get_type(), i32_vec_type);
+
+    let extracted = builder.build_extract_element(inserted, two, "extracted").into_int_value();
+
+    assert_eq!(extracted.get_type(), i32_type);
+
+    let mask = VectorType::const_vector(&[
+        i32_type.const_int(0, false),
+        i32_type.const_int(4, false),
+        i32_type.const_int(1, false),
+        i32_type.const_int(5, false),
+    ]);
+    let shuffled = builder.build_shuffle_vector(inserted, right, mask, "shuffled");
+
+    assert_eq!(shuffled.get_type(), i32_vec_type);
+
+    let shuffled = builder.build_insert_element(shuffled, extracted, i32_type.const_int(3, false), "shuffled");
+
+    builder.build_return(Some(&shuffled));
+
+    assert!(fn_value.verify(true));
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("%inserted = insertelement <4 x i32> %0, i32 %2, i32 2"));
+    assert!(ir.contains("%extracted = extractelement <4 x i32> %inserted, i32 2"));
+    assert!(ir.contains("%shuffled = shufflevector <4 x i32> %inserted, <4 x i32> %1, <4 x i32> <i32 0, i32 4, i32 1, i32 5>"));
+}
+
+#[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+#[test]
+fn test_invoke_landing_pad() {
+    let context = Context::create();
+    let module = context.create_module("test");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let i8_ptr_type = context.i8_type().ptr_type(AddressSpace::Generic);
+    let exception_type = context.struct_type(&[i8_ptr_type.into(), i32_type.into()], false);
+    let personality_type = i32_type.fn_type(&[], true);
+    let personality = module.add_function("__gxx_personality_v0", &personality_type, None);
+    let type_info = module.add_global(i8_ptr_type, None, "_ZTIi");
+    let may_throw_type = i32_type.fn_type(&[i32_type.into()], false);
+    let may_throw = module.add_function("may_throw", &may_throw_type, None);
+    let fn_type = i32_type.fn_type(&[], false);
+    let fn_value = module.add_function("call_may_throw", &fn_type, None);
+    let entry_bb = fn_value.append_basic_block("entry");
+    let cont_bb = fn_value.append_basic_block("cont");
+    let lpad_bb = fn_value.append_basic_block("lpad");
+
+    assert!(!fn_value.has_personality_function());
+
+    builder.position_at_end(&entry_bb);
+
+    let result = builder.build_invoke(may_throw, &[i32_type.const_int(42, false).into()], &cont_bb, &lpad_bb, "result")
+                        .left()
+                        .unwrap()
+                        .into_int_value();
+
+    assert_eq!(result.as_instruction().unwrap().get_opcode(), InstructionOpcode::Invoke);
+
+    builder.position_at_end(&cont_bb);
+    builder.build_return(Some(&result));
+    builder.position_at_end(&lpad_bb);
+
+    let exception = builder.build_landing_pad(exception_type, &personality, 1, "exception");
+    let landing_pad = exception.as_instruction().unwrap();
+
+    assert_eq!(landing_pad.get_opcode(), InstructionOpcode::LandingPad);
+    assert!(!landing_pad.is_cleanup());
+
+    landing_pad.add_clause(type_info.as_pointer_value());
+    landing_pad.set_cleanup(true);
+
+    assert!(landing_pad.is_cleanup());
+
+    let resume = builder.build_resume(exception);
+
+    assert_eq!(resume.get_opcode(), InstructionOpcode::Resume);
+    assert!(fn_value.has_personality_function());
+    assert_eq!(fn_value.get_personality_function().unwrap(), personality);
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("%result = invoke i32 @may_throw(i32 42)"));
+    assert!(ir.contains("to label %cont unwind label %lpad"));
+    assert!(ir.contains("%exception = landingpad { i8*, i32 }"));
+    assert!(ir.contains("cleanup"));
+    assert!(ir.contains("catch i8** @_ZTIi"));
+    assert!(ir.contains("resume { i8*, i32 } %exception"));
+}
+
+#[test]
+fn test_gep() {
+    let context = Context::create();
+    let module = context.create_module("test");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let i64_type = context.i64_type();
+    let struct_type = context.struct_type(&[i32_type.into(), i64_type.into()], false);
+    let struct_ptr_type = struct_type.ptr_type(AddressSpace::Generic);
+    let i32_ptr_type = i32_type.ptr_type(AddressSpace::Generic);
+    let fn_type = i64_type.fn_type(&[struct_ptr_type.into(), i32_ptr_type.into()], false);
+    let fn_value = module.add_function("load_field", &fn_type, None);
+    let entry = fn_value.append_basic_block("entry");
+    let struct_ptr = fn_value.get_first_param().unwrap().into_pointer_value();
+    let i32_ptr = fn_value.get_last_param().unwrap().into_pointer_value();
+
+    builder.position_at_end(&entry);
+
+    assert!(builder.build_struct_gep(struct_ptr, 2, "out_of_bounds").is_err());
+    assert!(builder.build_struct_gep(i32_ptr, 0, "not_a_struct").is_err());
+    assert!(entry.get_first_instruction().is_none());
+
+    let field_ptr = builder.build_struct_gep(struct_ptr, 1, "field_ptr").unwrap();
+
+    assert_eq!(field_ptr.get_type(), i64_type.ptr_type(AddressSpace::Generic));
+
+    let zero = i32_type.const_int(0, false);
+    let one = i32_type.const_int(1, false);
+    let same_field_ptr = unsafe { builder.build_gep(struct_ptr, &[zero, one], "same_field_ptr") };
+    let next_ptr = unsafe { builder.build_in_bounds_gep(i32_ptr, &[one], "next_ptr") };
+
+    assert_eq!(same_field_ptr.get_type(), field_ptr.get_type());
+    assert_eq!(next_ptr.get_type(), i32_ptr_type);
+
+    let field = builder.build_load(field_ptr, "field");
+
+    builder.build_return(Some(&field));
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("%field_ptr = getelementptr inbounds { i32, i64 }, { i32, i64 }* %0, i32 0, i32 1"));
+    assert!(ir.contains("%same_field_ptr = getelementptr { i32, i64 }, { i32, i64 }* %0, i32 0, i32 1"));
+    assert!(ir.contains("%next_ptr = getelementptr inbounds i32, i32* %1, i32 1"));
+}
+
+#[test]
+fn test_cast_family() {
+    let context = Context::create();
+    let module = context.create_module("test");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let i64_type = context.i64_type();
+    let f32_type = context.f32_type();
+    let f64_type = context.f64_type();
+    let global_ptr_type = i32_type.ptr_type(AddressSpace::Global);
+    let generic_ptr_type = i32_type.ptr_type(AddressSpace::Generic);
+    let fn_type = context.void_type().fn_type(&[i64_type.into(), f64_type.into(), global_ptr_type.into()], false);
+    let fn_value = module.add_function("casts", &fn_type, None);
+    let entry = fn_value.append_basic_block("entry");
+    let int = fn_value.get_first_param().unwrap().into_int_value();
+    let double = fn_value.get_nth_param(1).unwrap().into_float_value();
+    let global_ptr = fn_value.get_last_param().unwrap().into_pointer_value();
+
+    builder.position_at_end(&entry);
+
+    let truncated = builder.build_int_truncate(int, i32_type, "truncated");
+    let extended = builder.build_int_s_extend(truncated, i64_type, "extended");
+    let float = builder.build_float_trunc(double, f32_type, "float");
+    let bits = builder.build_bitcast(float, i32_type, "bits");
+    let generic_ptr = builder.build_address_space_cast(global_ptr, generic_ptr_type, "generic_ptr");
+
+    assert_eq!(truncated.get_type(), i32_type);
+    assert_eq!(extended.get_type(), i64_type);
+    assert_eq!(float.get_type(), f32_type);
+    assert_eq!(bits.into_int_value().get_type(), i32_type);
+    assert_eq!(generic_ptr.get_type(), generic_ptr_type);
+
+    builder.build_return(None);
+
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("%truncated = trunc i64 %0 to i32\n  %extended = sext i32 %truncated to i64"));
+    assert!(ir.contains("%float = fptrunc double %1 to float"));
+    assert!(ir.contains("%bits = bitcast float %float to i32"));
+    assert!(ir.contains("%generic_ptr = addrspacecast i32 addrspace(1)* %2 to i32*"));
+}
+
+#[test]
+fn test_phi_diamond() {
+    let context = Context::create();
+    let module = context.create_module("test");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let bool_type = context.bool_type();
+    let fn_type = i32_type.fn_type(&[bool_type.into(), i32_type.into()], false);
+    let fn_value = module.add_function("diamond", &fn_type, None);
+    let entry = fn_value.append_basic_block("entry");
+    let then_block = fn_value.append_basic_block("then");
+    let else_block = fn_value.append_basic_block("else");
+    let merge = fn_value.append_basic_block("merge");
+    let abort = fn_value.append_basic_block("abort");
+    let cond = fn_value.get_first_param().unwrap().into_int_value();
+    let value = fn_value.get_last_param().unwrap().into_int_value();
+
+    builder.position_at_end(&entry);
+    builder.build_conditional_branch(&cond, &then_block, &else_block);
+
+    builder.position_at_end(&then_block);
+
+    let doubled = builder.build_int_add(value, value, "doubled");
+
+    builder.build_unconditional_branch(&merge);
+
+    builder.position_at_end(&else_block);
+
+    let negated = builder.build_int_neg(&value, "negated");
+
+    builder.build_unconditional_branch(&merge);
+
+    builder.position_at_end(&merge);
+
+    let phi = builder.build_phi(i32_type, "result");
+
+    phi.add_incoming(&[
+        (&doubled, &then_block),
+        (&negated, &else_block),
+    ]);
+
+    assert_eq!(phi.count_incoming(), 2);
+    assert_eq!(phi.get_incoming(1).unwrap().1, else_block);
+
+    builder.build_return(Some(&phi.as_basic_value()));
+
+    // Blocks without any predecessors still need a terminator
+    builder.position_at_end(&abort);
+
+    let unreachable = builder.build_unreachable();
+
+    assert_eq!(unreachable.get_opcode(), InstructionOpcode::Unreachable);
+    assert!(module.verify().is_ok());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("%result = phi i32 [ %doubled, %then ], [ %negated, %else ]"));
+    assert!(ir.contains("; No predecessors!\n  unreachable"));
+}
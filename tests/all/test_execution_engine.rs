@@ -2,10 +2,13 @@ extern crate inkwell;
 
 use self::inkwell::{AddressSpace, OptimizationLevel, IntPredicate};
 use self::inkwell::context::Context;
-use self::inkwell::execution_engine::{ExecutionEngine, FunctionLookupError};
+use self::inkwell::execution_engine::{ExecutionEngine, FunctionLookupError, RemoveModuleError};
+use self::inkwell::memory_buffer::MemoryBuffer;
 use self::inkwell::targets::{InitializationConfig, Target};
 
 // use std::ffi::CString;
+use std::mem::transmute;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 type Thunk = unsafe extern "C" fn();
 
@@ -48,6 +51,85 @@ fn test_get_function_address() {
     }
 }
 
+#[test]
+fn test_get_function_address_and_call() {
+    Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
+
+    let context = Context::create();
+    let module = context.create_module("add");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+    let fn_value = module.add_function("add", &fn_type, None);
+    let basic_block = fn_value.append_basic_block("entry");
+    let lhs = fn_value.get_first_param().unwrap().into_int_value();
+    let rhs = fn_value.get_last_param().unwrap().into_int_value();
+
+    builder.position_at_end(&basic_block);
+
+    let sum = builder.build_int_add(lhs, rhs, "sum");
+
+    builder.build_return(Some(&sum));
+
+    let execution_engine = module.create_interpreter_execution_engine().unwrap();
+
+    assert_eq!(execution_engine.get_function_address("add"), Err(FunctionLookupError::JITNotEnabled));
+
+    let module = module.clone();
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    assert_eq!(execution_engine.get_function_address("sub"), Err(FunctionLookupError::FunctionNotFound));
+
+    let address = execution_engine.get_function_address("add").unwrap();
+
+    assert_ne!(address, 0);
+
+    let add: unsafe extern "C" fn(i32, i32) -> i32 = unsafe { transmute(address as usize) };
+
+    assert_eq!(unsafe { add(40, 2) }, 42);
+}
+
+static HOST_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+extern "C" fn host_callback(value: i32) {
+    HOST_CALLS.fetch_add(value as usize, Ordering::SeqCst);
+}
+
+#[test]
+fn test_add_global_mapping() {
+    Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
+
+    let context = Context::create();
+    let module = context.create_module("host_calls");
+    let builder = context.create_builder();
+    let void_type = context.void_type();
+    let i32_type = context.i32_type();
+    let callback_type = void_type.fn_type(&[i32_type.into()], false);
+    let callback = module.add_function("host_callback", &callback_type, None);
+    let fn_type = void_type.fn_type(&[], false);
+    let fn_value = module.add_function("caller", &fn_type, None);
+    let basic_block = fn_value.append_basic_block("entry");
+
+    builder.position_at_end(&basic_block);
+    builder.build_call(callback, &[i32_type.const_int(3, false).into()], "call", false);
+    builder.build_return(None);
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    execution_engine.add_global_mapping(&callback, host_callback as usize);
+
+    assert_eq!(execution_engine.get_function_address("host_callback"), Ok(host_callback as usize as u64));
+
+    unsafe {
+        let caller = execution_engine.get_function::<Thunk>("caller").unwrap();
+
+        caller();
+        caller();
+    }
+
+    assert_eq!(HOST_CALLS.load(Ordering::SeqCst), 6);
+}
+
 #[test]
 fn test_jit_execution_engine() {
     let context = Context::create();
@@ -138,6 +220,172 @@ fn test_interpreter_execution_engine() {
     assert!(module.create_interpreter_execution_engine().is_ok());
 }
 
+#[test]
+fn test_interpreter_get_global_value_address() {
+    let context = Context::create();
+    let module = context.create_module("main_module");
+    let i32_type = context.i32_type();
+    let global = module.add_global(i32_type, None, "my_global");
+
+    global.set_initializer(&i32_type.const_int(7, false));
+
+    Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
+
+    let execution_engine = module.create_interpreter_execution_engine().unwrap();
+
+    unsafe {
+        assert!(execution_engine.get_global_value_address("my_global").is_none());
+    }
+}
+
+#[test]
+fn test_interpreter_run_function() {
+    Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
+    ExecutionEngine::link_in_interpreter();
+
+    let context = Context::create();
+    let module = context.create_module("main_module");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+    let add = module.add_function("add", &fn_type, None);
+    let entry = add.append_basic_block("entry");
+
+    builder.position_at_end(&entry);
+
+    let lhs = add.get_first_param().unwrap().into_int_value();
+    let rhs = add.get_last_param().unwrap().into_int_value();
+    let sum = builder.build_int_add(lhs, rhs, "sum");
+
+    builder.build_return(Some(&sum));
+
+    let f64_type = context.f64_type();
+    let fn_type = f64_type.fn_type(&[f64_type.into()], false);
+    let halve = module.add_function("halve", &fn_type, None);
+    let entry = halve.append_basic_block("entry");
+
+    builder.position_at_end(&entry);
+
+    let x = halve.get_first_param().unwrap().into_float_value();
+    let halved = builder.build_float_mul(x, f64_type.const_float(0.5), "halved");
+
+    builder.build_return(Some(&halved));
+
+    assert!(module.verify().is_ok());
+
+    let execution_engine = module.create_interpreter_execution_engine().unwrap();
+    let two = i32_type.create_generic_value(2, false);
+    let minus_five = i32_type.create_generic_value(-5i64 as u64, true);
+
+    assert_eq!(two.int_width(), 32);
+    assert_eq!(two.as_int(false), 2);
+    assert_eq!(minus_five.as_int(true) as i64, -5);
+
+    let result = unsafe {
+        execution_engine.run_function(&add, &[&two, &minus_five])
+    };
+
+    assert_eq!(result.int_width(), 32);
+    assert_eq!(result.as_int(true) as i64, -3);
+
+    let three = f64_type.create_generic_value(3.);
+
+    assert_eq!(three.as_float(&f64_type), 3.);
+
+    let result = unsafe {
+        execution_engine.run_function(&halve, &[&three])
+    };
+
+    assert_eq!(result.as_float(&f64_type), 1.5);
+}
+
+#[test]
+fn test_run_static_constructors_and_destructors() {
+    Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
+
+    let context = Context::create();
+    let ir = b"@counter = global i32 0
+@llvm.global_ctors = appending global [1 x { i32, void ()*, i8* }] [{ i32, void ()*, i8* } { i32 65535, void ()* @init, i8* null }]
+@llvm.global_dtors = appending global [1 x { i32, void ()*, i8* }] [{ i32, void ()*, i8* } { i32 65535, void ()* @fini, i8* null }]
+
+define void @init() {
+entry:
+  store i32 42, i32* @counter
+  ret void
+}
+
+define void @fini() {
+entry:
+  store i32 7, i32* @counter
+  ret void
+}
+
+define i32 @get_counter() {
+entry:
+  %counter = load i32, i32* @counter
+  ret i32 %counter
+}
+";
+    let memory_buffer = MemoryBuffer::create_from_memory_range_copy(ir, "my_ir");
+    let module = context.create_module_from_ir(memory_buffer).unwrap();
+
+    assert!(module.verify().is_ok());
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    unsafe {
+        // Looking up a function finalizes the EE's code
+        let get_counter = execution_engine.get_function::<unsafe extern "C" fn() -> i32>("get_counter").unwrap();
+
+        assert_eq!(get_counter(), 0);
+
+        execution_engine.run_static_constructors();
+
+        assert_eq!(get_counter(), 42);
+
+        execution_engine.run_static_destructors();
+
+        assert_eq!(get_counter(), 7);
+    }
+}
+
+#[test]
+fn test_get_global_value_address() {
+    Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
+
+    let context = Context::create();
+    let module = context.create_module("globals");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let global = module.add_global(i32_type, None, "my_global");
+
+    global.set_initializer(&i32_type.const_int(7, false));
+
+    let fn_type = i32_type.fn_type(&[], false);
+    let get_global = module.add_function("get_global", &fn_type, None);
+    let entry = get_global.append_basic_block("entry");
+
+    builder.position_at_end(&entry);
+
+    let value = builder.build_load(global.as_pointer_value(), "value");
+
+    builder.build_return(Some(&value));
+
+    let execution_engine = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    unsafe {
+        let address = execution_engine.get_global_value_address("my_global").unwrap();
+
+        assert_eq!(*(address as *const i32), 7);
+
+        // Writes from Rust are visible to JIT compiled code
+        *(address as *mut i32) = 13;
+
+        let get_global = execution_engine.get_function::<unsafe extern "C" fn() -> i32>("get_global").unwrap();
+
+        assert_eq!(get_global(), 13);
+    }
+}
 
 #[test]
 fn test_add_remove_module() {
@@ -157,6 +405,73 @@ fn test_add_remove_module() {
     assert!(ee.remove_module(&module2).is_ok());
 }
 
+#[test]
+fn test_cross_module_call() {
+    Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
+
+    let context = Context::create();
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[], false);
+
+    // Module B defines helper
+    let module_b = context.create_module("b");
+    let helper = module_b.add_function("helper", &fn_type, None);
+    let basic_block = helper.append_basic_block("entry");
+
+    builder.position_at_end(&basic_block);
+    builder.build_return(Some(&i32_type.const_int(42, false)));
+
+    // Module A declares helper and calls it from main
+    let module_a = context.create_module("a");
+    let helper_decl = module_a.add_function("helper", &fn_type, None);
+    let main = module_a.add_function("main", &fn_type, None);
+    let basic_block = main.append_basic_block("entry");
+
+    builder.position_at_end(&basic_block);
+
+    let ret = builder.build_call(helper_decl, &[], "call", false).left().unwrap().into_int_value();
+
+    builder.build_return(Some(&ret));
+
+    let ee = module_a.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    assert!(ee.add_module(&module_b).is_ok());
+    assert!(ee.add_module(&module_b).is_err());
+
+    unsafe {
+        let main = ee.get_function::<unsafe extern "C" fn() -> i32>("main").unwrap();
+
+        assert_eq!(main(), 42);
+    }
+}
+
+#[test]
+fn test_remove_module_then_drop() {
+    Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
+
+    let context = Context::create();
+    let module = context.create_module("test");
+    let module2 = context.create_module("test2");
+    let module3 = context.create_module("test3");
+    let ee = module.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+    let ee2 = module3.create_jit_execution_engine(OptimizationLevel::None).unwrap();
+
+    assert_eq!(ee.remove_module(&module2), Err(RemoveModuleError::ModuleNotOwned));
+    assert!(ee.add_module(&module2).is_ok());
+    assert_eq!(ee2.remove_module(&module2), Err(RemoveModuleError::IncorrectModuleOwner));
+    assert!(ee.remove_module(&module2).is_ok());
+    assert_eq!(ee.remove_module(&module2), Err(RemoveModuleError::ModuleNotOwned));
+
+    // module2 is no longer owned by the EE, so it must be safely disposed of here
+    drop(module2);
+
+    assert!(ee.remove_module(&module).is_ok());
+
+    drop(ee);
+    drop(module);
+}
+
 // REVIEW: Global state pollution access tests cause this to pass when run individually
 // but fail when multiple tests are run
 // #[test]
@@ -2,8 +2,8 @@ extern crate inkwell;
 
 use self::inkwell::context::Context;
 use self::inkwell::memory_buffer::MemoryBuffer;
-use self::inkwell::module::Module;
-use self::inkwell::OptimizationLevel;
+use self::inkwell::module::{FlagBehavior, Linkage, Module, VerifierFailureAction};
+use self::inkwell::{AddressSpace, OptimizationLevel};
 use self::inkwell::targets::{InitializationConfig, Target};
 
 use std::env::temp_dir;
@@ -90,6 +90,39 @@ fn test_get_function() {
     assert_eq!(first_fn, some_fn);
     assert_eq!(last_fn, some_fn);
     assert_eq!(named_fn, some_fn);
+
+    let other_fn = module.add_function("other_fn", &some_fn_type, None);
+    let mut functions = module.get_functions();
+
+    assert_eq!(functions.next().unwrap(), some_fn);
+    assert_eq!(functions.next().unwrap(), other_fn);
+    assert!(functions.next().is_none());
+    assert_eq!(module.get_functions().filter(|f| f.count_basic_blocks() == 0).count(), 2);
+    assert_eq!(module.get_function_count(), 2);
+}
+
+#[test]
+fn test_get_or_add_function() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let fn_value = module.get_or_add_function("my_fn", &fn_type, Some(Linkage::Private));
+
+    assert_eq!(fn_value.get_linkage(), Linkage::Private);
+    assert_eq!(module.get_or_add_function("my_fn", &fn_type, None), fn_value);
+    assert_eq!(module.get_function_count(), 1);
+
+    // An existing function is returned as is, even with a mismatched type
+    let other_fn_type = context.void_type().fn_type(&[], false);
+
+    assert_eq!(module.get_or_add_function("my_fn", &other_fn_type, None), fn_value);
+    assert_eq!(module.get_function_count(), 1);
+
+    let other_fn = module.get_or_add_function("other_fn", &other_fn_type, None);
+
+    assert_ne!(other_fn, fn_value);
+    assert_eq!(module.get_function_count(), 2);
 }
 
 #[test]
@@ -124,10 +157,24 @@ fn test_write_and_load_memory_buffer() {
     assert_eq!(module2.get_function("my_fn").unwrap().print_to_string(), function.print_to_string());
 }
 
+#[test]
+fn test_bitcode_memory_buffer_slice() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let memory_buffer = module.write_bitcode_to_memory();
+    let slice = memory_buffer.as_slice();
+
+    assert_eq!(slice.len(), memory_buffer.get_size());
+    assert!(slice.starts_with(b"BC\xC0\xDE"));
+}
+
 #[test]
 fn test_garbage_ir_fails_create_module_from_ir() {
     let context = Context::create();
-    let memory_buffer = MemoryBuffer::create_from_memory_range("garbage ir data", "my_ir");
+    // The IR parser reads one past the end of its input, so it must be followed by a nul
+    let memory_buffer = unsafe {
+        MemoryBuffer::create_from_memory_range(&b"garbage ir data\0"[..15], "my_ir")
+    };
 
     assert_eq!(memory_buffer.get_size(), 15);
     assert_eq!(from_utf8(memory_buffer.as_slice()).unwrap(), "garbage ir data");
@@ -137,13 +184,85 @@ fn test_garbage_ir_fails_create_module_from_ir() {
 #[test]
 fn test_garbage_ir_fails_create_module_from_ir_copy() {
     let context = Context::create();
-    let memory_buffer = MemoryBuffer::create_from_memory_range_copy("garbage ir data", "my_ir");
+    let memory_buffer = MemoryBuffer::create_from_memory_range_copy(b"garbage ir data", "my_ir");
 
     assert_eq!(memory_buffer.get_size(), 15);
     assert_eq!(from_utf8(memory_buffer.as_slice()).unwrap(), "garbage ir data");
     assert!(context.create_module_from_ir(memory_buffer).is_err());
 }
 
+#[test]
+fn test_parse_bitcode_from_memory_range() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+    let function = module.add_function("my_fn", &fn_type, None);
+    let basic_block = function.append_basic_block("entry");
+    let builder = context.create_builder();
+
+    builder.position_at_end(&basic_block);
+    builder.build_return(None);
+
+    let mut path = temp_dir();
+
+    path.push("memory_range.bc");
+
+    assert!(module.write_bitcode_to_path(&path));
+
+    let mut bitcode = Vec::new();
+
+    File::open(&path).unwrap().read_to_end(&mut bitcode).unwrap();
+    remove_file(&path).unwrap();
+
+    assert_eq!(&bitcode[..4], b"BC\xC0\xDE");
+
+    let memory_buffer = MemoryBuffer::create_from_memory_range_copy(&bitcode, "my_bitcode");
+
+    drop(bitcode);
+
+    let module2 = Module::parse_bitcode_from_buffer_in_context(&memory_buffer, &context).unwrap();
+
+    assert_eq!(module2.get_function("my_fn").unwrap().print_to_string(), function.print_to_string());
+
+    let memory_buffer2 = unsafe {
+        MemoryBuffer::create_from_memory_range(memory_buffer.as_slice(), "my_bitcode2")
+    };
+
+    assert_eq!(memory_buffer2.get_size(), memory_buffer.get_size());
+    assert!(Module::parse_bitcode_from_buffer_in_context(&memory_buffer2, &context).is_ok());
+}
+
+#[test]
+fn test_verify_error_display() {
+    use std::error::Error;
+
+    fn verify(module: &Module) -> Result<(), Box<Error>> {
+        module.verify()?;
+
+        Ok(())
+    }
+
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+    let function = module.add_function("my_fn", &fn_type, None);
+
+    function.append_basic_block("entry");
+
+    let error = module.verify().unwrap_err();
+    let message = format!("{}", error);
+
+    assert!(message.starts_with("Basic Block in function 'my_fn' does not have terminator!"), "{}", message);
+    assert_eq!(message, error.to_string());
+    assert_eq!(error.description(), message);
+
+    let boxed_error = verify(&module).unwrap_err();
+
+    assert_eq!(boxed_error.to_string(), message);
+}
+
 #[test]
 fn test_get_type() {
     let context = Context::create();
@@ -170,6 +289,43 @@ fn test_get_type_global_context() {
     assert_eq!(module.get_type("foo").unwrap().into_struct_type(), opaque);
 }
 
+#[test]
+fn test_get_context_repeatedly() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+
+    for _ in 0..100 {
+        let context_ref = module.get_context();
+
+        assert_eq!(*context_ref, context);
+        assert_eq!(module.get_context(), context_ref);
+
+        drop(context_ref);
+    }
+
+    // The original context must still be usable
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[], false);
+    let function = module.add_function("my_fn", &fn_type, None);
+    let builder = context.create_builder();
+    let entry = function.append_basic_block("entry");
+
+    builder.position_at_end(&entry);
+    builder.build_return(Some(&i32_type.const_int(1, false)));
+
+    assert!(module.verify().is_ok());
+
+    // A ContextRef may also outlive both the Module and the original Context
+    let context_ref = module.get_context();
+
+    drop(module);
+    drop(context);
+
+    let module2 = context_ref.create_module("my_module2");
+
+    assert_eq!(*module2.get_context(), *context_ref);
+}
+
 #[test]
 fn test_module_no_double_free() {
     let _module = {
@@ -199,7 +355,9 @@ fn test_owned_module_dropped_ee_and_context() {
 #[test]
 fn test_parse_from_buffer() {
     let context = Context::create();
-    let garbage_buffer = MemoryBuffer::create_from_memory_range("garbage ir data", "my_ir");
+    let garbage_buffer = unsafe {
+        MemoryBuffer::create_from_memory_range(b"garbage ir data", "my_ir")
+    };
     let module_result = Module::parse_bitcode_from_buffer(&garbage_buffer);
 
     assert!(module_result.is_err());
@@ -294,6 +452,36 @@ fn test_clone() {
     assert_eq!(module.print_to_string(), module2.print_to_string());
 }
 
+#[test]
+fn test_clone_into_context() {
+    let global_context = Context::get_global();
+    let module = Module::create("my_mod");
+    let fn_type = global_context.void_type().fn_type(&[], false);
+    let f = module.add_function("f", &fn_type, None);
+    let basic_block = f.append_basic_block("entry");
+    let builder = global_context.create_builder();
+
+    builder.position_at_end(&basic_block);
+    builder.build_return(None);
+
+    module.add_function("g", &fn_type, None);
+
+    let context = Context::create();
+    let module2 = module.clone_into_context(&context).unwrap();
+
+    assert_ne!(module.get_context(), module2.get_context());
+    assert_eq!(*module2.get_context(), context);
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+    assert_eq!(module2.get_name().to_str(), Ok("my_mod"));
+
+    let names = |module: &Module| -> Vec<String> {
+        module.get_functions().map(|f| f.get_name().to_string_lossy().into_owned()).collect()
+    };
+
+    assert_eq!(names(&module), names(&module2));
+    assert!(module2.verify().is_ok());
+}
+
 #[test]
 fn test_print_to_file() {
     let context = Context::create();
@@ -331,4 +519,309 @@ fn test_get_set_target() {
     module.set_target(&target);
 
     assert_eq!(module.get_target().unwrap(), target);
+
+    // The Target mustn't be tied to the module's triple string, which it only borrows
+    let target2 = module.get_target().unwrap();
+    let fn_type = context.void_type().fn_type(&[], false);
+
+    module.add_function("my_fn", &fn_type, None);
+    module.add_global(context.i8_type(), None, "my_global");
+    module.set_inline_assembly("nop");
+
+    assert_eq!(target2.get_name().to_str(), Ok("x86-64"));
+    assert_eq!(module.get_target().unwrap(), target2);
+
+    drop(module);
+
+    assert_eq!(target2, target);
+    assert_eq!(target2.get_name().to_str(), Ok("x86-64"));
+}
+
+#[test]
+fn test_set_inline_assembly() {
+    let context = Context::create();
+    let module = context.create_module("mod");
+
+    module.set_inline_assembly(".text\n.globl my_symbol");
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("module asm \".text\"\nmodule asm \".globl my_symbol\""));
+
+    module.set_inline_assembly("nop");
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("module asm \"nop\""));
+    assert!(!ir.contains("my_symbol"));
+}
+
+#[test]
+fn test_get_set_triple() {
+    let context = Context::create();
+    let module = context.create_module("mod");
+
+    assert_eq!(module.get_triple().to_bytes(), b"");
+
+    // The triple's target needn't be initialized or even built into LLVM
+    module.set_triple("riscv64-unknown-none-elf");
+
+    let triple = module.get_triple();
+
+    assert_eq!(triple.to_str(), Ok("riscv64-unknown-none-elf"));
+    assert!(module.print_to_string().to_string().contains("target triple = \"riscv64-unknown-none-elf\""));
+
+    module.set_triple("x86_64-unknown-linux-gnu");
+
+    // The earlier triple is a copy, so it is unaffected
+    assert_eq!(triple.to_str(), Ok("riscv64-unknown-none-elf"));
+    assert_eq!(module.get_triple().to_str(), Ok("x86_64-unknown-linux-gnu"));
+}
+
+#[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7")))]
+#[test]
+fn test_linking_modules() {
+    let context = Context::create();
+    let module = context.create_module("mod");
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+    let fn_val = module.add_function("f", &fn_type, None);
+    let basic_block = fn_val.append_basic_block("entry");
+    let builder = context.create_builder();
+
+    builder.position_at_end(&basic_block);
+    builder.build_return(None);
+
+    let module2 = context.create_module("mod2");
+    let callee = module2.add_function("f", &fn_type, None);
+    let caller = module2.add_function("f2", &fn_type, None);
+    let basic_block2 = caller.append_basic_block("entry");
+
+    builder.position_at_end(&basic_block2);
+    builder.build_call(callee, &[], "call", false);
+    builder.build_return(None);
+
+    assert!(module.link_in_module(module2).is_ok());
+    assert_eq!(module.get_function("f").unwrap(), fn_val);
+    assert!(module.get_function("f2").is_some());
+    assert!(module.verify().is_ok());
+
+    // Linking in a second definition of the same function should fail
+    let module3 = context.create_module("mod3");
+    let fn_val3 = module3.add_function("f", &fn_type, None);
+    let basic_block3 = fn_val3.append_basic_block("entry");
+
+    builder.position_at_end(&basic_block3);
+    builder.build_return(None);
+
+    assert!(module.link_in_module(module3).is_err());
+}
+
+#[test]
+fn test_get_globals() {
+    let context = Context::create();
+    let module = context.create_module("mod");
+    let i8_type = context.i8_type();
+
+    assert!(module.get_globals().next().is_none());
+
+    let global = module.add_global(i8_type, None, "global");
+    let global2 = module.add_global(i8_type, Some(AddressSpace::Generic), "global2");
+    let global3 = module.add_global(i8_type, Some(AddressSpace::Const), "global3");
+    let mut globals = module.get_globals();
+
+    assert_eq!(globals.next().unwrap(), global);
+    assert_eq!(globals.next().unwrap(), global2);
+    assert_eq!(globals.next().unwrap(), global3);
+    assert!(globals.next().is_none());
+    assert_eq!(module.get_global_count(), 3);
+}
+
+#[test]
+fn test_global_in_custom_address_space() {
+    let context = Context::create();
+    let module = context.create_module("mod");
+    let i8_type = context.i8_type();
+
+    module.add_global(i8_type, Some(AddressSpace::from(5)), "local_global");
+    module.add_global(i8_type, Some(AddressSpace::Custom(7)), "custom_global");
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("@local_global = external addrspace(5) global i8"));
+    assert!(ir.contains("@custom_global = external addrspace(7) global i8"));
+    assert_eq!(AddressSpace::from(5), AddressSpace::Local);
+    assert_eq!(AddressSpace::Custom(1), AddressSpace::Global);
+    assert_eq!(u32::from(AddressSpace::from(42)), 42);
+}
+
+#[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+#[test]
+fn test_get_set_name() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+
+    assert_eq!(*module.get_name(), *CString::new("my_module").unwrap());
+
+    module.set_name("my_renamed_module");
+
+    assert_eq!(*module.get_name(), *CString::new("my_renamed_module").unwrap());
+}
+
+#[test]
+fn test_module_flags() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let i32_type = context.i32_type();
+    let md_string = context.metadata_string("some metadata");
+
+    assert_eq!(module.get_global_metadata_size("llvm.module.flags"), 0);
+
+    module.add_basic_value_flag(FlagBehavior::Warning, "Debug Info Version", i32_type.const_int(3, false));
+    module.add_module_flag(FlagBehavior::Error, "my_flag", md_string);
+
+    assert_eq!(module.get_global_metadata_size("llvm.module.flags"), 2);
+    assert!(module.verify().is_ok());
+
+    let flags = module.get_global_metadata("llvm.module.flags");
+
+    assert_eq!(flags[0].get_node_size(), 3);
+    assert_eq!(flags[1].get_node_size(), 3);
+}
+
+#[cfg(feature = "llvm6-0")]
+#[test]
+fn test_strip_debug_info() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let md_node = context.metadata_node(&[]);
+
+    assert!(!module.strip_debug_info());
+
+    module.add_global_metadata("llvm.dbg.cu", &md_node);
+    module.add_global_metadata("my_md", &md_node);
+
+    assert_eq!(module.get_global_metadata_size("llvm.dbg.cu"), 1);
+    assert!(module.strip_debug_info());
+    assert_eq!(module.get_global_metadata_size("llvm.dbg.cu"), 0);
+    assert_eq!(module.get_global_metadata_size("my_md"), 1);
+    assert!(module.verify().is_ok());
+    assert!(!module.strip_debug_info());
+}
+
+#[test]
+fn test_add_global_alias() {
+    let context = Context::create();
+    let module = context.create_module("mod");
+    let i32_type = context.i32_type();
+    let global = module.add_global(i32_type, None, "my_global");
+
+    global.set_initializer(&i32_type.const_int(42, false));
+
+    let alias = module.add_global_alias(i32_type.ptr_type(AddressSpace::Generic), &global, "my_alias");
+
+    assert_eq!(alias.get_linkage(), Linkage::External);
+
+    alias.set_linkage(Linkage::WeakAny);
+
+    assert_eq!(alias.get_linkage(), Linkage::WeakAny);
+    assert_eq!(*alias.as_pointer_value().get_name(), *CString::new("my_alias").unwrap());
+    assert!(module.get_global("my_alias").is_none());
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_delete_function() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+    let fn_value = module.add_function("my_fn", &fn_type, None);
+    let fn_value2 = module.add_function("my_fn2", &fn_type, None);
+
+    unsafe {
+        fn_value.delete();
+    }
+
+    assert!(module.get_function("my_fn").is_none());
+    assert_eq!(module.get_function("my_fn2").unwrap(), fn_value2);
+    assert_eq!(module.get_functions().collect::<Vec<_>>(), vec![fn_value2]);
+}
+
+#[test]
+fn test_delete_global() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let i8_type = context.i8_type();
+    let global = module.add_global(i8_type, None, "my_global");
+    let global2 = module.add_global(i8_type, None, "my_global2");
+
+    unsafe {
+        global.delete();
+    }
+
+    assert!(module.get_global("my_global").is_none());
+    assert_eq!(module.get_global("my_global2").unwrap(), global2);
+    assert_eq!(module.get_globals().collect::<Vec<_>>(), vec![global2]);
+}
+
+#[test]
+fn test_module_display() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+
+    module.add_function("my_fn", &fn_type, None);
+
+    let display = format!("{}", module);
+
+    assert!(display.contains("ModuleID = 'my_module'"));
+    assert!(display.contains("declare void @my_fn()"));
+    assert_eq!(display, module.print_to_string().to_string());
+    assert!(format!("{:?}", module).contains("declare void @my_fn()"));
+}
+
+#[test]
+fn test_verify_with_action() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let builder = context.create_builder();
+    let fn_type = context.void_type().fn_type(&[], false);
+    let fn_value = module.add_function("my_fn", &fn_type, None);
+    let entry = fn_value.append_basic_block("entry");
+
+    assert!(module.verify_with_action(VerifierFailureAction::ReturnStatus).is_err());
+    assert!(module.verify_with_action(VerifierFailureAction::PrintMessage).is_err());
+    assert!(module.verify().is_err());
+
+    builder.position_at_end(&entry);
+    builder.build_return(None);
+
+    assert!(module.verify_with_action(VerifierFailureAction::ReturnStatus).is_ok());
+    assert!(module.verify_with_action(VerifierFailureAction::PrintMessage).is_ok());
+    assert!(module.verify_with_action(VerifierFailureAction::AbortProcess).is_ok());
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_global_metadata_ident() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let md_string = context.metadata_string("clang version");
+
+    assert!(module.get_global_metadata("llvm.ident").is_empty());
+
+    module.add_global_metadata("llvm.ident", &md_string);
+
+    let ident = module.get_global_metadata("llvm.ident");
+
+    assert_eq!(ident.len(), 1);
+    assert!(ident[0].is_node());
+
+    let ident_values = ident[0].get_node_values();
+
+    assert_eq!(ident_values.len(), 1);
+    assert_eq!(ident_values[0].as_metadata_value().get_string_value(), Some(&*CString::new("clang version").unwrap()));
+    assert!(module.print_to_string().to_string_lossy().contains("!llvm.ident = !{!0}"));
 }
@@ -3,12 +3,13 @@ extern crate inkwell;
 use self::inkwell::context::Context;
 use self::inkwell::passes::{PassManagerBuilder, PassManager, PassRegistry};
 use self::inkwell::OptimizationLevel::Aggressive;
+use self::inkwell::values::{FunctionValue, InstructionOpcode};
 
 #[test]
 fn test_init_all_passes_for_module() {
     let context = Context::create();
     let module = context.create_module("my_module");
-    let pass_manager = PassManager::create_for_module();
+    let pass_manager = PassManager::create();
 
     pass_manager.add_argument_promotion_pass();
     pass_manager.add_constant_merge_pass();
@@ -73,15 +74,102 @@ fn test_init_all_passes_for_module() {
         pass_manager.add_new_gvn_pass();
     }
 
-    assert!(!pass_manager.initialize());
-    assert!(!pass_manager.finalize());
+    pass_manager.run_on(&module);
+}
+
+#[test]
+fn test_module_pass_manager_shrinks_redundant_arithmetic() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let fn_value = module.add_function("my_fn", &fn_type, None);
+    let entry = fn_value.append_basic_block("entry");
+
+    builder.position_at_end(&entry);
+
+    let x = fn_value.get_first_param().unwrap().into_int_value();
+    let add1 = builder.build_int_add(x, x, "add1");
+    let add2 = builder.build_int_add(x, x, "add2");
+    let add3 = builder.build_int_add(add1, add2, "add3");
+
+    builder.build_return(Some(&add3));
+
+    assert!(module.verify().is_ok());
+
+    let count_instructions = |fn_value: &FunctionValue| {
+        let mut count = 0;
+        let mut instruction = fn_value.get_first_basic_block().unwrap().get_first_instruction();
+
+        while let Some(current) = instruction {
+            count += 1;
+            instruction = current.get_next_instruction();
+        }
+
+        count
+    };
+
+    assert_eq!(count_instructions(&fn_value), 4);
+
+    let pass_manager = PassManager::create();
+
+    pass_manager.add_gvn_pass();
+    pass_manager.add_instruction_combining_pass();
+
+    assert!(pass_manager.run_on(&module));
+    assert!(module.verify().is_ok());
+    assert!(count_instructions(&fn_value) < 4);
+}
+
+#[test]
+fn test_function_pass_manager_promotes_allocas() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let fn_value = module.add_function("my_fn", &fn_type, None);
+    let entry = fn_value.append_basic_block("entry");
+
+    builder.position_at_end(&entry);
+
+    let x = fn_value.get_first_param().unwrap().into_int_value();
+    let slot = builder.build_alloca(i32_type, "slot");
+
+    builder.build_store(slot, x);
 
-    pass_manager.run_on_module(&module);
+    let loaded = builder.build_load(slot, "loaded");
 
-    assert!(!pass_manager.initialize());
-    assert!(!pass_manager.finalize());
+    builder.build_return(Some(&loaded));
 
-    // TODO: Test when initialize and finalize are true
+    let has_alloca = |fn_value: &FunctionValue| {
+        let mut instruction = fn_value.get_first_basic_block().unwrap().get_first_instruction();
+
+        while let Some(current) = instruction {
+            if current.get_opcode() == InstructionOpcode::Alloca {
+                return true;
+            }
+
+            instruction = current.get_next_instruction();
+        }
+
+        false
+    };
+
+    assert!(has_alloca(&fn_value));
+
+    let fpm = PassManager::create_function_pass_manager(&module);
+
+    fpm.add_promote_memory_to_register_pass();
+    fpm.initialize();
+
+    assert!(fpm.run_on(&fn_value));
+
+    fpm.finalize();
+
+    assert!(!has_alloca(&fn_value));
+    assert!(module.verify().is_ok());
 }
 
 #[test]
@@ -98,7 +186,7 @@ fn test_pass_manager_builder() {
     let context = Context::create();
     let module = context.create_module("my_module");
 
-    let fn_pass_manager = PassManager::create_for_function(&module);
+    let fn_pass_manager = PassManager::create_function_pass_manager(&module);
 
     pass_manager_builder.populate_function_pass_manager(&fn_pass_manager);
 
@@ -114,22 +202,65 @@ fn test_pass_manager_builder() {
     // TODO: Test with actual changes? Would be true in that case
     // REVIEW: Segfaults in 4.0
     #[cfg(not(feature = "llvm4-0"))]
-    assert!(!fn_pass_manager.run_on_function(&fn_value));
+    assert!(!fn_pass_manager.run_on(&fn_value));
 
-    let module_pass_manager = PassManager::create_for_module();
+    let module_pass_manager = PassManager::create();
 
     pass_manager_builder.populate_module_pass_manager(&module_pass_manager);
 
     // TODOC: Seems to return true in 3.7 & 6.0, even though no changes were made.
     // In 3.6, 3.8, & 3.9 it returns false. Seems like a LLVM bug?
     #[cfg(not(any(feature = "llvm3-7", feature = "llvm6-0")))]
-    assert!(!module_pass_manager.run_on_module(&module));
+    assert!(!module_pass_manager.run_on(&module));
     #[cfg(any(feature = "llvm3-7", feature = "llvm6-0"))]
-    assert!(module_pass_manager.run_on_module(&module));
+    assert!(module_pass_manager.run_on(&module));
 
     // TODO: Populate LTO pass manager?
 }
 
+#[test]
+fn test_pass_manager_builder_aggressive_module() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let callee = module.add_function("callee", &fn_type, None);
+    let entry = callee.append_basic_block("entry");
+
+    builder.position_at_end(&entry);
+
+    let x = callee.get_first_param().unwrap().into_int_value();
+    let doubled = builder.build_int_mul(x, i32_type.const_int(2, false), "doubled");
+
+    builder.build_return(Some(&doubled));
+
+    let caller = module.add_function("caller", &fn_type, None);
+    let entry = caller.append_basic_block("entry");
+
+    builder.position_at_end(&entry);
+
+    let y = caller.get_first_param().unwrap();
+    let call = builder.build_call(callee, &[y], "call", false).left().unwrap();
+
+    builder.build_return(Some(&call));
+
+    assert!(module.verify().is_ok());
+
+    let pass_manager_builder = PassManagerBuilder::create();
+
+    pass_manager_builder.set_optimization_level(Aggressive);
+    pass_manager_builder.set_size_level(0);
+    pass_manager_builder.set_inliner_with_threshold(275);
+
+    let pass_manager = PassManager::create();
+
+    pass_manager_builder.populate_module_pass_manager(&pass_manager);
+
+    assert!(pass_manager.run_on(&module));
+    assert!(module.verify().is_ok());
+}
+
 #[test]
 fn test_pass_registry() {
     let pass_registry = PassRegistry::get_global();
@@ -2,9 +2,11 @@ extern crate inkwell;
 
 use self::inkwell::{AddressSpace, OptimizationLevel};
 use self::inkwell::context::Context;
-use self::inkwell::targets::{ByteOrdering, CodeModel, InitializationConfig, RelocMode, Target, TargetData, TargetMachine};
+use self::inkwell::targets::{ByteOrdering, CodeModel, FileType, InitializationConfig, RelocMode, Target, TargetData, TargetMachine};
 
+use std::env::temp_dir;
 use std::ffi::CString;
+use std::fs::{metadata, remove_file};
 
 // REVIEW: Inconsistently failing on different tries :(
 // #[test]
@@ -60,6 +62,47 @@ use std::ffi::CString;
 //     target.get_next().expect("Did not find any target2");
 // }
 
+#[test]
+fn test_target_iteration() {
+    Target::initialize_all(&InitializationConfig::default());
+
+    let mut targets = Vec::new();
+    let mut target = Target::get_first();
+
+    while let Some(current) = target {
+        target = current.get_next();
+        targets.push(current);
+    }
+
+    assert!(!targets.is_empty());
+
+    for target in &targets {
+        assert!(!target.get_name().to_bytes().is_empty());
+        assert!(!target.get_description().to_bytes().is_empty());
+        assert_eq!(Target::from_name(target.get_name().to_str().unwrap()).as_ref(), Some(target));
+    }
+
+    assert!(targets.iter().any(|target| target.has_target_machine()));
+}
+
+#[test]
+fn test_target_from_triple() {
+    Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
+
+    let default_triple = TargetMachine::get_default_triple();
+    let target = Target::from_triple(default_triple.to_str().unwrap()).unwrap();
+
+    assert!(target.has_target_machine());
+
+    let target_machine = target.create_target_machine(default_triple.to_str().unwrap(), "", "", OptimizationLevel::Default, RelocMode::Default, CodeModel::Default).unwrap();
+
+    assert_eq!(target_machine.get_target(), target);
+
+    let error = Target::from_triple("bogus-unknown-unknown").unwrap_err();
+
+    assert!(error.to_string().contains("triple"), "{}", error);
+}
+
 #[test]
 fn test_target_and_target_machine() {
     let bad_target = Target::from_name("asd");
@@ -112,10 +155,109 @@ fn test_target_and_target_machine() {
     assert_eq!(*target_machine.get_feature_string(), *CString::new("+avx2").unwrap());
 }
 
+#[test]
+fn test_write_target_machine_to_file() {
+    Target::initialize_x86(&InitializationConfig::default());
+
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let builder = context.create_builder();
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+    let fn_value = module.add_function("my_fn", &fn_type, None);
+    let basic_block = fn_value.append_basic_block("entry");
+
+    builder.position_at_end(&basic_block);
+    builder.build_return(None);
+
+    let target = Target::from_name("x86-64").unwrap();
+    let target_machine = target.create_target_machine("x86_64-pc-linux-gnu", "x86-64", "", OptimizationLevel::Default, RelocMode::Default, CodeModel::Default).unwrap();
+
+    let mut object_path = temp_dir();
+
+    object_path.push("inkwell_target_machine.o");
+
+    assert!(target_machine.write_to_file(&module, FileType::Object, &object_path).is_ok());
+    assert!(metadata(&object_path).unwrap().len() > 0);
+
+    remove_file(&object_path).unwrap();
+
+    let mut asm_path = temp_dir();
+
+    asm_path.push("inkwell_target_machine.s");
+
+    assert!(target_machine.write_to_file(&module, FileType::Assembly, &asm_path).is_ok());
+    assert!(metadata(&asm_path).unwrap().len() > 0);
+
+    remove_file(&asm_path).unwrap();
+}
+
+#[test]
+fn test_write_target_machine_to_memory_buffer() {
+    Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
+
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+    let fn_value = module.add_function("add", &fn_type, None);
+    let basic_block = fn_value.append_basic_block("entry");
+    let lhs = fn_value.get_first_param().unwrap().into_int_value();
+    let rhs = fn_value.get_last_param().unwrap().into_int_value();
+
+    builder.position_at_end(&basic_block);
+
+    let sum = builder.build_int_add(lhs, rhs, "sum");
+
+    builder.build_return(Some(&sum));
+
+    let triple = TargetMachine::get_default_triple();
+    let target = Target::from_triple(triple.to_str().unwrap()).unwrap();
+    let target_machine = target.create_target_machine(triple.to_str().unwrap(), "", "", OptimizationLevel::Default, RelocMode::Default, CodeModel::Default).unwrap();
+    let buffer = target_machine.write_to_memory_buffer(&module, FileType::Object).unwrap();
+    let bytes = buffer.as_slice();
+
+    #[cfg(target_os = "linux")]
+    assert_eq!(&bytes[..4], b"\x7fELF");
+
+    #[cfg(target_os = "macos")]
+    assert_eq!(&bytes[..4], &[0xcf, 0xfa, 0xed, 0xfe]);
+
+    #[cfg(target_os = "windows")]
+    assert_eq!(&bytes[..2], &[0x64, 0x86]);
+
+    let asm_buffer = target_machine.write_to_memory_buffer(&module, FileType::Assembly).unwrap();
+
+    assert!(asm_buffer.get_size() > 0);
+}
+
+#[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+#[test]
+fn test_target_machine_target_data() {
+    Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
+
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let triple = TargetMachine::get_default_triple();
+    let target = Target::from_triple(triple.to_str().unwrap()).unwrap();
+    let target_machine = target.create_target_machine(triple.to_str().unwrap(), "", "", OptimizationLevel::Default, RelocMode::Default, CodeModel::Default).unwrap();
+    let target_data = target_machine.get_target_data();
+    let data_layout = target_data.get_data_layout();
+
+    assert!(!data_layout.as_str().to_bytes().is_empty());
+
+    module.set_data_layout(&data_layout);
+
+    assert_eq!(*module.get_data_layout(), data_layout);
+}
+
 #[test]
 fn test_default_target_triple() {
     let default_target_triple = TargetMachine::get_default_triple();
 
+    assert!(!default_target_triple.to_bytes().is_empty());
+
     #[cfg(target_os = "linux")]
     let cond = *default_target_triple == *CString::new("x86_64-pc-linux-gnu").unwrap() ||
                *default_target_triple == *CString::new("x86_64-unknown-linux-gnu").unwrap();
@@ -250,6 +392,59 @@ fn test_target_data() {
     TargetData::create("e-m:e-i64:64-f80:128-n8:16:32:64-S128");
 }
 
+#[test]
+fn test_packed_struct_offsets() {
+    let context = Context::create();
+    let target_data = TargetData::create("e-m:e-i64:64-f80:128-n8:16:32:64-S128");
+    let i8_type = context.i8_type();
+    let i32_type = context.i32_type();
+    let i64_type = context.i64_type();
+    let field_types = [i8_type.into(), i32_type.into(), i8_type.into(), i64_type.into()];
+    let struct_type = context.struct_type(&field_types, false);
+    let packed_struct_type = context.struct_type(&field_types, true);
+
+    assert_eq!(target_data.offset_of_element(&struct_type, 0), Some(0));
+    assert_eq!(target_data.offset_of_element(&struct_type, 1), Some(4));
+    assert_eq!(target_data.offset_of_element(&struct_type, 2), Some(8));
+    assert_eq!(target_data.offset_of_element(&struct_type, 3), Some(16));
+    assert_eq!(target_data.get_abi_size(&struct_type), 24);
+
+    assert_eq!(target_data.offset_of_element(&packed_struct_type, 0), Some(0));
+    assert_eq!(target_data.offset_of_element(&packed_struct_type, 1), Some(1));
+    assert_eq!(target_data.offset_of_element(&packed_struct_type, 2), Some(5));
+    assert_eq!(target_data.offset_of_element(&packed_struct_type, 3), Some(6));
+    assert_eq!(target_data.get_abi_size(&packed_struct_type), 14);
+
+    assert_eq!(target_data.element_at_offset(&struct_type, 3), 0);
+    assert_eq!(target_data.element_at_offset(&struct_type, 6), 1);
+    assert_eq!(target_data.element_at_offset(&packed_struct_type, 3), 1);
+    assert_eq!(target_data.element_at_offset(&packed_struct_type, 6), 3);
+
+    let empty_struct_type = context.struct_type(&[], false);
+
+    assert!(target_data.offset_of_element(&empty_struct_type, 0).is_none());
+}
+
+#[test]
+fn test_target_data_from_module_data_layout() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let data_layout = TargetData::create("e-m:e-p:64:64-i64:64-f80:128-n8:16:32:64-S128").get_data_layout();
+
+    module.set_data_layout(&data_layout);
+
+    // A Module's DataLayout must be converted into a TargetData in order to be queried
+    let target_data = TargetData::create(module.get_data_layout().as_str().to_str().unwrap());
+    let i64_type = context.i64_type();
+
+    assert_eq!(target_data.get_abi_size(&i64_type), 8);
+    assert_eq!(target_data.get_store_size(&i64_type), 8);
+    assert_eq!(target_data.get_abi_alignment(&i64_type), 8);
+    assert_eq!(target_data.get_preferred_alignment(&i64_type), 8);
+    assert_eq!(target_data.get_pointer_byte_size(None), 8);
+    assert_eq!(target_data.get_pointer_byte_size(Some(AddressSpace::Generic)), 8);
+}
+
 #[test]
 fn test_ptr_sized_int() {
     Target::initialize_native(&InitializationConfig::default()).expect("Failed to initialize native target");
@@ -4,7 +4,7 @@ use std::ffi::CString;
 
 use self::inkwell::AddressSpace;
 use self::inkwell::context::Context;
-use self::inkwell::types::{FloatType, IntType, StructType, VoidType};
+use self::inkwell::types::{BasicType, FloatType, IntType, PointerType, StructType, VectorType, VoidType};
 
 #[test]
 fn test_struct_type() {
@@ -99,6 +99,74 @@ fn test_struct_type() {
     }
 }
 
+#[test]
+fn test_struct_type_fields() {
+    let context = Context::create();
+    let i32_type = context.i32_type();
+    let i8_ptr_type = context.i8_type().ptr_type(AddressSpace::Generic);
+    let struct_type = context.struct_type(&[i32_type.into(), i8_ptr_type.into()], false);
+
+    assert!(!struct_type.is_packed());
+    assert!(!struct_type.is_opaque());
+    assert!(struct_type.get_name().is_none());
+    assert_eq!(struct_type.count_fields(), 2);
+    assert_eq!(struct_type.get_field_types(), vec![i32_type.into(), i8_ptr_type.into()]);
+
+    #[cfg(not(feature = "llvm3-6"))]
+    {
+        assert_eq!(struct_type.get_field_type_at_index(0).unwrap().into_int_type(), i32_type);
+        assert_eq!(struct_type.get_field_type_at_index(1).unwrap().into_pointer_type(), i8_ptr_type);
+        assert!(struct_type.get_field_type_at_index(2).is_none());
+    }
+}
+
+#[test]
+fn test_recursive_struct_type() {
+    let context = Context::create();
+    let module = context.create_module("my_module");
+    let node_type = context.opaque_struct_type("Node");
+    let i32_type = context.i32_type();
+    let node_ptr_type = node_type.ptr_type(AddressSpace::Generic);
+
+    assert!(node_type.is_opaque());
+    assert_eq!(node_type.count_fields(), 0);
+    assert!(node_type.set_body(&[&i32_type, &node_ptr_type], false));
+    assert!(!node_type.is_opaque());
+    assert_eq!(node_type.count_fields(), 2);
+    assert_eq!(node_type.get_field_types(), vec![i32_type.into(), node_ptr_type.into()]);
+
+    // Already has a body
+    assert!(!node_type.set_body(&[&i32_type], false));
+    assert_eq!(node_type.count_fields(), 2);
+
+    let global = module.add_global(node_type, None, "head");
+
+    global.set_initializer(&node_type.const_null());
+
+    assert!(module.verify().is_ok());
+
+    let empty_type = context.opaque_struct_type("Empty");
+
+    assert!(empty_type.set_body(&[], false));
+    assert!(!empty_type.is_opaque());
+    assert_eq!(empty_type.count_fields(), 0);
+}
+
+#[test]
+fn test_extended_float_types() {
+    let context = Context::create();
+    let x86_f80_type = context.x86_f80_type();
+    let f128_type = context.f128_type();
+    let ppc_f128_type = context.ppc_f128_type();
+
+    assert_eq!(*x86_f80_type.print_to_string(), *CString::new("x86_fp80").unwrap());
+    assert_eq!(*f128_type.print_to_string(), *CString::new("fp128").unwrap());
+    assert_eq!(*ppc_f128_type.print_to_string(), *CString::new("ppc_fp128").unwrap());
+    assert_eq!(*x86_f80_type.get_context(), context);
+    assert_eq!(*f128_type.get_context(), context);
+    assert_eq!(*ppc_f128_type.get_context(), context);
+}
+
 #[test]
 fn test_function_type() {
     let context = Context::create();
@@ -122,6 +190,137 @@ fn test_function_type() {
     assert_eq!(*fn_type.get_context(), context);
 }
 
+#[test]
+fn test_any_type_is_sized() {
+    use self::inkwell::types::AnyType;
+
+    fn is_sized<T: AnyType>(type_: &T) -> bool {
+        type_.is_sized()
+    }
+
+    let context = Context::create();
+    let i32_type = context.i32_type();
+    let struct_type = context.struct_type(&[i32_type.into()], false);
+    let opaque_struct_type = context.opaque_struct_type("opaque");
+
+    assert!(is_sized(&struct_type));
+    assert!(struct_type.as_any_type_enum().is_sized());
+    assert!(struct_type.as_basic_type_enum().is_sized());
+    assert!(!is_sized(&opaque_struct_type));
+    assert!(!opaque_struct_type.as_any_type_enum().is_sized());
+    assert!(!is_sized(&i32_type.fn_type(&[], false)));
+    assert!(!is_sized(&context.void_type()));
+    assert!(!is_sized(&context.label_type()));
+
+    opaque_struct_type.set_body(&[&i32_type], false);
+
+    assert!(is_sized(&opaque_struct_type));
+}
+
+#[test]
+fn test_print_type_to_string() {
+    use self::inkwell::types::AnyType;
+
+    fn print<T: AnyType>(type_: &T) -> String {
+        type_.print_to_string().to_string()
+    }
+
+    let context = Context::create();
+    let i32_type = context.i32_type();
+    let array_type = i32_type.array_type(4);
+    let struct_type = context.opaque_struct_type("my_struct");
+
+    assert_eq!(print(&array_type), "[4 x i32]");
+    assert_eq!(print(&struct_type), "%my_struct = type opaque");
+    assert_eq!(print(&i32_type.fn_type(&[i32_type.into()], true)), "i32 (i32, ...)");
+    assert_eq!(print(&context.void_type()), "void");
+    assert_eq!(array_type.as_any_type_enum().to_string(), "[4 x i32]");
+    assert_eq!(format!("{}", array_type.as_basic_type_enum()), "[4 x i32]");
+    assert_eq!(format!("{}", i32_type.ptr_type(AddressSpace::Generic).as_basic_type_enum()), "i32*");
+}
+
+#[test]
+fn test_type_kind() {
+    use self::inkwell::types::{AnyType, TypeKind};
+
+    let context = Context::create();
+    let i8_type = context.i8_type();
+    let i32_type = context.i32_type();
+    let f32_type = context.f32_type();
+    let array_type = i8_type.array_type(2);
+    let struct_type = context.struct_type(&[i32_type.into()], false);
+    let fn_type = context.void_type().fn_type(&[], false);
+
+    assert_eq!(i32_type.as_any_type_enum().get_kind(), TypeKind::Integer);
+    assert_eq!(array_type.as_any_type_enum().get_kind(), TypeKind::Array);
+    assert_eq!(f32_type.as_any_type_enum().get_kind(), TypeKind::Float);
+    assert_eq!(context.f16_type().as_any_type_enum().get_kind(), TypeKind::Half);
+    assert_eq!(struct_type.as_any_type_enum().get_kind(), TypeKind::Struct);
+    assert_eq!(i8_type.ptr_type(AddressSpace::Generic).as_any_type_enum().get_kind(), TypeKind::Pointer);
+    assert_eq!(i32_type.vec_type(4).as_any_type_enum().get_kind(), TypeKind::Vector);
+    assert_eq!(fn_type.as_any_type_enum().get_kind(), TypeKind::Function);
+    assert_eq!(context.void_type().as_any_type_enum().get_kind(), TypeKind::Void);
+    assert_eq!(context.label_type().as_any_type_enum().get_kind(), TypeKind::Label);
+}
+
+#[test]
+fn test_special_types() {
+    use self::inkwell::types::AnyType;
+
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let builder = context.create_builder();
+    let void_type = context.void_type();
+    let fn_type = void_type.fn_type(&[], false);
+    let fn_value = module.add_function("my_fn", &fn_type, None);
+    let entry = fn_value.append_basic_block("entry");
+
+    builder.position_at_end(&entry);
+    builder.build_return(None);
+
+    assert!(fn_type.get_return_type().is_none());
+    assert!(module.verify().is_ok());
+
+    let label_type = context.label_type();
+
+    assert_eq!(*label_type.get_context(), context);
+    assert_eq!(*label_type.print_to_string(), *CString::new("label").unwrap());
+    assert_eq!(label_type.as_any_type_enum(), label_type.into());
+
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8", feature = "llvm3-9", feature = "llvm4-0", feature = "llvm5-0")))]
+    {
+        let metadata_type = context.metadata_type();
+        let token_type = context.token_type();
+
+        assert_eq!(*metadata_type.get_context(), context);
+        assert_eq!(*metadata_type.print_to_string(), *CString::new("metadata").unwrap());
+        assert!(metadata_type.as_any_type_enum().is_metadata_type());
+        assert_eq!(*token_type.get_context(), context);
+        assert_eq!(*token_type.print_to_string(), *CString::new("token").unwrap());
+        assert!(token_type.as_any_type_enum().is_token_type());
+    }
+}
+
+#[test]
+fn test_function_type_signature() {
+    let context = Context::create();
+    let i8_type = context.i8_type();
+    let i32_type = context.i32_type();
+    let f64_type = context.f64_type();
+    let fn_type = i32_type.fn_type(&[i8_type.into(), f64_type.into()], false);
+
+    assert_eq!(fn_type.get_return_type(), Some(i32_type.into()));
+    assert_eq!(fn_type.count_param_types(), 2);
+    assert_eq!(fn_type.get_param_types(), vec![i8_type.into(), f64_type.into()]);
+    assert!(!fn_type.is_var_arg());
+
+    let void_fn_type = context.void_type().fn_type(&[], true);
+
+    assert!(void_fn_type.get_return_type().is_none());
+    assert!(void_fn_type.get_param_types().is_empty());
+    assert!(void_fn_type.is_var_arg());
+}
+
 #[test]
 fn test_sized_types() {
     let context = Context::get_global();
@@ -330,6 +529,67 @@ fn test_vec_type() {
     let vec_type = int.vec_type(42);
 
     assert_eq!(vec_type.get_size(), 42);
+    assert_eq!(vec_type.get_element_type().into_int_type(), int);
+
+    let i32_type = context.i32_type();
+    let vec_type = i32_type.vec_type(4);
+    let f64_vec_type = context.f64_type().vec_type(2);
+
+    assert_eq!(vec_type.get_size(), 4);
+    assert_eq!(*vec_type.print_to_string(), *CString::new("<4 x i32>").unwrap());
+    assert_eq!(f64_vec_type.get_size(), 2);
+    assert!(f64_vec_type.get_element_type().is_float_type());
+
+    let vec_value = VectorType::const_vector(&[i32_type.const_int(1, false), i32_type.const_int(2, false), i32_type.const_int(3, false), i32_type.const_int(4, false)]);
+
+    assert_eq!(vec_value.get_type(), vec_type);
+}
+
+#[test]
+#[should_panic]
+fn test_zero_sized_vec_type() {
+    let context = Context::create();
+
+    context.i32_type().vec_type(0);
+}
+
+#[test]
+fn test_get_element_type() {
+    let context = Context::create();
+    let i8_type = context.i8_type();
+    let i8_array_type = i8_type.array_type(4);
+    let i8_ptr_type = i8_type.ptr_type(AddressSpace::Generic);
+    let i8_vec_type = i8_type.vec_type(4);
+    let i8_ptr_array_type = i8_ptr_type.array_type(2);
+
+    assert_eq!(i8_array_type.get_element_type().into_int_type(), i8_type);
+    assert_eq!(i8_ptr_type.get_element_type().into_int_type(), i8_type);
+    assert_eq!(i8_vec_type.get_element_type().into_int_type(), i8_type);
+    assert_eq!(i8_ptr_array_type.get_element_type().into_pointer_type(), i8_ptr_type);
+    assert_eq!(i8_ptr_array_type.get_element_type().into_pointer_type().get_element_type().into_int_type(), i8_type);
+}
+
+#[test]
+fn test_ptr_address_space() {
+    let context = Context::create();
+    let i8_type = context.i8_type();
+    let i8_ptr_type = i8_type.ptr_type(AddressSpace::Global);
+
+    assert_eq!(i8_ptr_type.get_address_space(), AddressSpace::Global);
+    assert_eq!(u32::from(i8_ptr_type.get_address_space()), 1);
+    assert_eq!(*i8_ptr_type.print_to_string(), *CString::new("i8 addrspace(1)*").unwrap());
+    assert_eq!(i8_ptr_type.get_element_type().into_int_type(), i8_type);
+
+    // Constructing through the BasicType trait should match the inherent method
+    fn basic_ptr_type<T: BasicType>(basic_type: &T, address_space: AddressSpace) -> PointerType {
+        basic_type.ptr_type(address_space)
+    }
+
+    let custom_ptr_type = basic_ptr_type(&i8_type, AddressSpace::Custom(9));
+
+    assert_eq!(custom_ptr_type.get_address_space(), AddressSpace::Custom(9));
+    assert_eq!(basic_ptr_type(&i8_type, AddressSpace::Global), i8_ptr_type);
+    assert_eq!(i8_type.ptr_type(AddressSpace::Generic).get_address_space(), AddressSpace::Generic);
 }
 
 #[test]
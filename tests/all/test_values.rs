@@ -1,9 +1,9 @@
 extern crate inkwell;
 
-use self::inkwell::{DLLStorageClass, FloatPredicate, GlobalVisibility, ThreadLocalMode, AddressSpace};
+use self::inkwell::{DLLStorageClass, FloatPredicate, GlobalVisibility, ThreadLocalMode, UnnamedAddress, AddressSpace};
 use self::inkwell::context::Context;
 use self::inkwell::module::Linkage::*;
-use self::inkwell::types::{StructType, VectorType};
+use self::inkwell::types::{StringRadix, StructType, VectorType};
 use self::inkwell::values::InstructionOpcode::*;
 use self::inkwell::values::{MetadataValue, FIRST_CUSTOM_METADATA_KIND_ID};
 
@@ -90,6 +90,47 @@ fn test_tail_call() {
     assert_eq!(call_instruction.right().unwrap().is_tail_call(), true);
 }
 
+#[test]
+fn test_call_conventions() {
+    let context = Context::create();
+    let module = context.create_module("testing");
+    let builder = context.create_builder();
+
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[], false);
+    let callee = module.add_function("callee", &fn_type, None);
+    let caller = module.add_function("caller", &fn_type, None);
+
+    assert_eq!(callee.get_call_conventions(), 0);
+
+    callee.set_call_conventions(8);
+
+    assert_eq!(callee.get_call_conventions(), 8);
+
+    let entry = callee.append_basic_block("entry");
+
+    builder.position_at_end(&entry);
+    builder.build_return(Some(&i32_type.const_int(1, false)));
+
+    let entry = caller.append_basic_block("entry");
+
+    builder.position_at_end(&entry);
+
+    let call_value = builder.build_call(callee, &[], "call", false).left().unwrap();
+    let call_instruction = call_value.as_instruction().unwrap();
+
+    assert_eq!(call_instruction.get_call_conventions(), 0);
+
+    call_instruction.set_call_conventions(8);
+
+    assert_eq!(call_instruction.get_call_conventions(), 8);
+
+    builder.build_return(Some(&call_value));
+
+    assert!(module.verify().is_ok());
+    assert!(module.print_to_string().to_string_lossy().contains("call fastcc i32 @callee()"));
+}
+
 #[test]
 fn test_set_get_name() {
     let context = Context::create();
@@ -333,6 +374,14 @@ fn test_consecutive_fns() {
 
     assert_eq!(function2.get_previous_function().unwrap(), function);
     assert!(function2.get_next_function().is_none());
+
+    let function3 = module.add_function("fn3", &fn_type, None);
+
+    assert_eq!(function2.get_next_function().unwrap(), function3);
+    assert_eq!(function3.get_previous_function().unwrap(), function2);
+    assert!(function3.get_next_function().is_none());
+    assert_eq!(function.get_next_function().unwrap().get_next_function().unwrap(), function3);
+    assert_eq!(function3.get_previous_function().unwrap().get_previous_function().unwrap(), function);
 }
 
 #[test]
@@ -362,6 +411,28 @@ fn test_verify_fn() {
     // TODO: Verify other verify modes
 }
 
+#[test]
+fn test_verify_fn_missing_terminator() {
+    let context = Context::create();
+    let builder = context.create_builder();
+    let module = context.create_module("fns");
+
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[], false);
+
+    let function = module.add_function("fn", &fn_type, None);
+    let basic_block = context.append_basic_block(&function, "entry");
+
+    builder.position_at_end(&basic_block);
+    builder.build_int_add(i32_type.const_int(1, false), i32_type.const_int(2, false), "sum");
+
+    assert!(!function.verify(false));
+
+    builder.build_return(Some(&i32_type.const_int(3, false)));
+
+    assert!(function.verify(false));
+}
+
 #[test]
 fn test_metadata() {
     let context = Context::create();
@@ -630,6 +701,84 @@ fn test_metadata() {
     assert!(context_metadata_string.is_string());
 }
 
+#[test]
+fn test_metadata_round_trip() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let i32_type = context.i32_type();
+    let i32_seven = i32_type.const_int(7, false);
+    let f64_half = context.f64_type().const_float(0.5);
+    let md_string = context.metadata_string("my string");
+    let md_node = context.metadata_node(&[i32_seven.into(), f64_half.into()]);
+
+    assert_eq!(md_string.get_string_value().unwrap().to_str(), Ok("my string"));
+    assert!(md_string.get_node_values().is_empty());
+    assert!(md_node.get_string_value().is_none());
+    assert_eq!(md_node.get_node_size(), 2);
+
+    module.add_global_metadata("my_md", &md_string);
+    module.add_global_metadata("my_md", &md_node);
+
+    let global_md = module.get_global_metadata("my_md");
+
+    assert_eq!(global_md.len(), 2);
+
+    let string_values = global_md[0].get_node_values();
+
+    assert_eq!(string_values.len(), 1);
+    assert_eq!(string_values[0].as_metadata_value().get_string_value().unwrap().to_str(), Ok("my string"));
+
+    let node_values = global_md[1].get_node_values();
+
+    assert_eq!(node_values.len(), 2);
+    assert_eq!(node_values[0].into_int_value(), i32_seven);
+    assert_eq!(node_values[1].into_float_value(), f64_half);
+}
+
+#[test]
+fn test_instruction_metadata() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let fn_value = module.add_function("my_fn", &fn_type, None);
+    let entry = fn_value.append_basic_block("entry");
+
+    builder.position_at_end(&entry);
+
+    let param = fn_value.get_first_param().unwrap().into_int_value();
+    let add = builder.build_int_add(param, param, "add");
+    let add_instruction = add.as_instruction().unwrap();
+
+    builder.build_return(Some(&add));
+
+    let kind_id = context.get_kind_id("my_custom_kind");
+
+    assert!(kind_id >= FIRST_CUSTOM_METADATA_KIND_ID);
+    assert_eq!(context.get_kind_id("my_custom_kind"), kind_id);
+    assert!(!add_instruction.has_metadata());
+    assert!(add_instruction.get_metadata(kind_id).is_none());
+
+    let i32_forty_two = i32_type.const_int(42, false);
+    let i32_seven = i32_type.const_int(7, false);
+    let md_node = context.metadata_node(&[i32_forty_two.into(), i32_seven.into()]);
+
+    add_instruction.set_metadata(&md_node, kind_id);
+
+    assert!(add_instruction.has_metadata());
+
+    let attached_md = add_instruction.get_metadata(kind_id).unwrap();
+    let node_values = attached_md.get_node_values();
+
+    assert_eq!(attached_md, md_node);
+    assert_eq!(node_values.len(), 2);
+    assert_eq!(node_values[0].into_int_value(), i32_forty_two);
+    assert_eq!(node_values[1].into_int_value(), i32_seven);
+    assert!(add_instruction.get_metadata(context.get_kind_id("prof")).is_none());
+    assert!(module.verify().is_ok());
+}
+
 #[test]
 fn test_floats() {
     let context = Context::create();
@@ -716,13 +865,12 @@ fn test_function_value_no_params() {
     // REVIEW: According to this, fn_value.get_type() is a void ptr??
     // assert_eq!(fn_value.get_type(), fn_type);
     assert_eq!(fn_value.count_params(), 0);
-    assert_eq!(fn_value.params().collect::<Vec<_>>().len(), 0);
+    assert_eq!(fn_value.get_param_iter().collect::<Vec<_>>().len(), 0);
     assert!(fn_value.get_first_param().is_none());
     assert!(fn_value.get_last_param().is_none());
     assert!(fn_value.get_nth_param(0).is_none());
-    // REVIEW: get_personality_function causes segfault in 3.8 - 6.0
-    // Probably LLVM bug if so, should document
-    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-8", feature = "llvm3-9", feature = "llvm4-0", feature = "llvm5-0", feature = "llvm6-0")))]
+    // REVIEW: get_personality_function segfaults without a personality function in 3.7 & 3.8
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
     assert!(fn_value.get_personality_function().is_none());
     #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
     assert!(!fn_value.has_personality_function());
@@ -734,23 +882,52 @@ fn test_function_value_no_params() {
 fn test_int_from_string() {
     let context = Context::create();
     let i8_type = context.i8_type();
-    let i8_val = i8_type.const_int_from_string("0121", 10);
+    let i8_val = i8_type.const_int_from_string("0121", StringRadix::Decimal).unwrap();
 
     assert_eq!(*i8_val.print_to_string(), *CString::new("i8 121").unwrap());
 
-    let i8_val = i8_type.const_int_from_string("0121", 3);
+    let i8_val = i8_type.const_int_from_string("0121", StringRadix::Octal).unwrap();
+
+    assert_eq!(*i8_val.print_to_string(), *CString::new("i8 81").unwrap());
+
+    let i8_val = i8_type.const_int_from_string("-101", StringRadix::Binary).unwrap();
+
+    assert_eq!(*i8_val.print_to_string(), *CString::new("i8 -5").unwrap());
+
+    let i8_val = i8_type.const_int_from_string("7f", StringRadix::Hexadecimal).unwrap();
+
+    assert_eq!(*i8_val.print_to_string(), *CString::new("i8 127").unwrap());
+
+    // Invalid digits for the radix are rejected rather than partially parsed
+    assert!(i8_type.const_int_from_string("0121", StringRadix::Binary).is_none());
+    assert!(i8_type.const_int_from_string("ABCD", StringRadix::Binary).is_none());
+    assert!(i8_type.const_int_from_string("9", StringRadix::Octal).is_none());
+    assert!(i8_type.const_int_from_string("", StringRadix::Decimal).is_none());
+    assert!(i8_type.const_int_from_string("-", StringRadix::Decimal).is_none());
+
+    let i128_type = context.i128_type();
+    let i128_val = i128_type.const_int_from_string("170141183460469231731687303715884105727", StringRadix::Decimal).unwrap();
 
-    assert_eq!(*i8_val.print_to_string(), *CString::new("i8 16").unwrap());
+    assert_eq!(*i128_val.print_to_string(), *CString::new("i128 170141183460469231731687303715884105727").unwrap());
+}
 
-    // LLVM will not throw an error, just parse until it can parse no more (and
-    // possibly spit out something completely unexpected):
-    let i8_val = i8_type.const_int_from_string("0121", 2);
+#[test]
+fn test_int_arbitrary_precision() {
+    let context = Context::create();
+    let i128_type = context.i128_type();
 
-    assert_eq!(*i8_val.print_to_string(), *CString::new("i8 3").unwrap());
+    let low_only = i128_type.const_int_arbitrary_precision(&[u64::max_value(), 0]);
+    let high_only = i128_type.const_int_arbitrary_precision(&[0, 1]);
+    let both = i128_type.const_int_arbitrary_precision(&[1, 2]);
 
-    let i8_val = i8_type.const_int_from_string("ABCD", 2);
+    assert_eq!(*low_only.print_to_string(), *CString::new("i128 18446744073709551615").unwrap());
+    assert_eq!(*high_only.print_to_string(), *CString::new("i128 18446744073709551616").unwrap());
+    assert_eq!(*both.print_to_string(), *CString::new("i128 36893488147419103233").unwrap());
 
-    assert_eq!(*i8_val.print_to_string(), *CString::new("i8 -15").unwrap());
+    let i64_type = context.i64_type();
+    let truncated = i64_type.const_int_arbitrary_precision(&[42, 7]);
+
+    assert_eq!(*truncated.print_to_string(), *CString::new("i64 42").unwrap());
 }
 
 #[test]
@@ -786,6 +963,104 @@ fn test_global_byte_array() {
     // TODO: Assert something?
 }
 
+#[test]
+fn test_const_string_and_array() {
+    let context = Context::create();
+    let string = context.const_string("hi", true);
+
+    assert_eq!(string.get_type(), context.i8_type().array_type(3));
+    assert_eq!(*string.print_to_string(), *CString::new("[3 x i8] c\"hi\\00\"").unwrap());
+
+    let string = context.const_string("hi", false);
+
+    assert_eq!(string.get_type(), context.i8_type().array_type(2));
+    assert_eq!(*string.print_to_string(), *CString::new("[2 x i8] c\"hi\"").unwrap());
+
+    let i32_type = context.i32_type();
+    let i32_array_type = i32_type.array_type(3);
+    let values = [i32_type.const_int(1, false), i32_type.const_int(2, false), i32_type.const_int(3, false)];
+    let i32_array = i32_array_type.const_array(&values);
+
+    assert_eq!(i32_array.get_type(), i32_array_type);
+    assert_eq!(*i32_array.print_to_string(), *CString::new("[3 x i32] [i32 1, i32 2, i32 3]").unwrap());
+}
+
+#[test]
+fn test_const_structs() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let i32_type = context.i32_type();
+    let i8_type = context.i8_type();
+    let i32_one = i32_type.const_int(1, false);
+    let i8_two = i8_type.const_int(2, false);
+    let anonymous = context.const_struct(&[i32_one.into(), i8_two.into()], false);
+
+    assert_eq!(*anonymous.print_to_string(), *CString::new("{ i32, i8 } { i32 1, i8 2 }").unwrap());
+    assert_eq!(anonymous.get_type(), context.struct_type(&[i32_type.into(), i8_type.into()], false));
+
+    let packed = context.const_struct(&[i32_one.into(), i8_two.into()], true);
+
+    assert_eq!(*packed.print_to_string(), *CString::new("<{ i32, i8 }> <{ i32 1, i8 2 }>").unwrap());
+
+    let struct_type = context.opaque_struct_type("my_struct");
+
+    struct_type.set_body(&[&i32_type, &i8_type], false);
+
+    let named = struct_type.const_named_struct(&[i32_one.into(), i8_two.into()]);
+
+    assert_eq!(named.get_type(), struct_type);
+    assert_eq!(*named.print_to_string(), *CString::new("%my_struct { i32 1, i8 2 }").unwrap());
+
+    let global = module.add_global(struct_type, None, "my_global");
+
+    global.set_initializer(&named);
+
+    assert!(module.print_to_string().to_string_lossy().contains("@my_global = global %my_struct { i32 1, i8 2 }"));
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_const_gep_and_casts() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let i32_type = context.i32_type();
+    let i8_type = context.i8_type();
+    let array_type = i32_type.array_type(4);
+    let global = module.add_global(array_type, None, "my_array");
+
+    global.set_initializer(&array_type.const_null());
+
+    let indexes = [i32_type.const_int(0, false), i32_type.const_int(2, false)];
+    let element_ptr = unsafe {
+        global.as_pointer_value().const_gep(&indexes)
+    };
+    let in_bounds_element_ptr = unsafe {
+        global.as_pointer_value().const_in_bounds_gep(&indexes)
+    };
+
+    assert_eq!(element_ptr.get_type(), i32_type.ptr_type(AddressSpace::Generic));
+    assert_eq!(in_bounds_element_ptr.get_type(), i32_type.ptr_type(AddressSpace::Generic));
+
+    let element_ptr_ir = element_ptr.print_to_string().to_string();
+
+    assert!(element_ptr_ir.starts_with("i32* getelementptr"), "{}", element_ptr_ir);
+    assert!(element_ptr_ir.ends_with("([4 x i32], [4 x i32]* @my_array, i32 0, i32 2)"), "{}", element_ptr_ir);
+
+    let i8_ptr_type = i8_type.ptr_type(AddressSpace::Generic);
+    let bit_cast = element_ptr.const_bit_cast(&i8_ptr_type);
+    let cast = element_ptr.const_cast(&i8_ptr_type);
+
+    assert_eq!(bit_cast.get_type(), i8_ptr_type);
+    assert_eq!(cast.get_type(), i8_ptr_type);
+    assert!(bit_cast.print_to_string().to_string().starts_with("i8* bitcast (i32* getelementptr"));
+
+    let table = module.add_global(i8_ptr_type, None, "my_table");
+
+    table.set_initializer(&bit_cast);
+
+    assert!(module.verify().is_ok());
+}
+
 #[test]
 fn test_globals() {
     let context = Context::create();
@@ -808,9 +1083,7 @@ fn test_globals() {
     assert!(global.is_declaration());
     assert!(!global.has_unnamed_addr());
     assert!(!global.is_externally_initialized());
-    // REVIEW: Segfaults in 4.0, 5.0, & 6.0
-    #[cfg(not(any(feature = "llvm4-0", feature = "llvm5-0", feature = "llvm6-0")))]
-    assert_eq!(global.get_section(), &*CString::new("").unwrap());
+    assert!(global.get_section().is_none());
     assert_eq!(global.get_dll_storage_class(), DLLStorageClass::default());
     assert_eq!(global.get_visibility(), GlobalVisibility::default());
     assert_eq!(module.get_first_global().unwrap(), global);
@@ -833,7 +1106,7 @@ fn test_globals() {
     assert!(global.has_unnamed_addr());
     assert!(global.is_constant());
     assert!(!global.is_declaration());
-    assert_eq!(global.get_section(), &*CString::new("not sure what goes here").unwrap());
+    assert_eq!(global.get_section(), Some(&*CString::new("not sure what goes here").unwrap()));
 
     global.set_dll_storage_class(DLLStorageClass::Export);
     global.set_thread_local(false);
@@ -879,6 +1152,295 @@ fn test_globals() {
     }
 }
 
+#[test]
+fn test_global_section() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let i32_type = context.i32_type();
+    let global = module.add_global(i32_type, None, "my_global");
+
+    global.set_initializer(&i32_type.const_int(1, false));
+
+    assert!(global.get_section().is_none());
+
+    global.set_section(".mydata");
+
+    assert_eq!(global.get_section().unwrap().to_str(), Ok(".mydata"));
+    assert!(module.print_to_string().to_string_lossy().contains("section \".mydata\""));
+}
+
+#[test]
+fn test_alignment() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let global = module.add_global(i32_type, None, "my_global");
+
+    global.set_initializer(&i32_type.const_int(1, false));
+
+    assert_eq!(global.get_alignment(), 0);
+
+    global.set_alignment(16);
+
+    assert_eq!(global.get_alignment(), 16);
+    assert!(module.print_to_string().to_string_lossy().contains("@my_global = global i32 1, align 16"));
+
+    let fn_type = context.void_type().fn_type(&[], false);
+    let function = module.add_function("my_fn", &fn_type, None);
+    let entry = function.append_basic_block("entry");
+
+    builder.position_at_end(&entry);
+
+    let ptr = global.as_pointer_value();
+    let load = builder.build_load(ptr, "load").as_instruction().unwrap();
+    let store = builder.build_store(ptr, i32_type.const_int(2, false));
+
+    load.set_alignment(8);
+    store.set_alignment(4);
+
+    assert_eq!(load.get_alignment(), 8);
+    assert_eq!(store.get_alignment(), 4);
+
+    builder.build_return(None);
+
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_thread_local_global() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let i32_type = context.i32_type();
+    let global = module.add_global(i32_type, None, "my_tls");
+
+    global.set_initializer(&i32_type.const_int(0, false));
+
+    assert!(!global.is_thread_local());
+
+    global.set_thread_local_mode(Some(ThreadLocalMode::LocalExecTLSModel));
+
+    assert!(global.is_thread_local());
+    assert_eq!(global.get_thread_local_mode(), Some(ThreadLocalMode::LocalExecTLSModel));
+    assert!(module.print_to_string().to_string_lossy().contains("@my_tls = thread_local(localexec) global i32 0"));
+
+    global.set_thread_local(false);
+
+    assert!(!global.is_thread_local());
+    assert!(global.get_thread_local_mode().is_none());
+}
+
+#[test]
+fn test_constant_global_initializer() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let i32_type = context.i32_type();
+    let i32_42 = i32_type.const_int(42, false);
+    let global = module.add_global(i32_type, None, "my_global");
+
+    assert!(global.get_initializer().is_none());
+    assert!(global.is_declaration());
+    assert!(!global.is_constant());
+
+    global.set_initializer(&i32_42);
+    global.set_constant(true);
+
+    assert_eq!(global.get_initializer().unwrap().into_int_value(), i32_42);
+    assert!(!global.is_declaration());
+    assert!(global.is_constant());
+    assert!(module.verify().is_ok());
+    assert!(module.print_to_string().to_string_lossy().contains("@my_global = constant i32 42"));
+}
+
+#[test]
+fn test_function_dll_storage_class() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let builder = context.create_builder();
+    let fn_type = context.void_type().fn_type(&[], false);
+    let fn_value = module.add_function("my_fn", &fn_type, None);
+    let entry = fn_value.append_basic_block("entry");
+
+    builder.position_at_end(&entry);
+    builder.build_return(None);
+
+    assert_eq!(fn_value.get_dll_storage_class(), DLLStorageClass::default());
+
+    fn_value.set_dll_storage_class(DLLStorageClass::Export);
+
+    assert_eq!(fn_value.get_dll_storage_class(), DLLStorageClass::Export);
+    assert!(module.print_to_string().to_string_lossy().contains("define dllexport void @my_fn()"));
+
+    fn_value.set_dll_storage_class(DLLStorageClass::Default);
+
+    assert_eq!(fn_value.get_dll_storage_class(), DLLStorageClass::Default);
+}
+
+#[test]
+fn test_function_visibility() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let builder = context.create_builder();
+    let fn_type = context.void_type().fn_type(&[], false);
+    let fn_value = module.add_function("my_fn", &fn_type, None);
+    let entry = fn_value.append_basic_block("entry");
+
+    builder.position_at_end(&entry);
+    builder.build_return(None);
+
+    assert_eq!(fn_value.get_visibility(), GlobalVisibility::default());
+
+    fn_value.set_visibility(GlobalVisibility::Hidden);
+
+    assert_eq!(fn_value.get_visibility(), GlobalVisibility::Hidden);
+    assert!(module.print_to_string().to_string_lossy().contains("define hidden void @my_fn()"));
+
+    fn_value.set_visibility(GlobalVisibility::Protected);
+
+    assert_eq!(fn_value.get_visibility(), GlobalVisibility::Protected);
+}
+
+#[cfg(not(feature = "llvm3-6"))]
+#[test]
+fn test_personality_function() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let personality_type = i32_type.fn_type(&[], true);
+    let personality = module.add_function("__gxx_personality_v0", &personality_type, None);
+    let fn_type = context.void_type().fn_type(&[], false);
+    let fn_value = module.add_function("my_fn", &fn_type, None);
+    let entry = fn_value.append_basic_block("entry");
+
+    builder.position_at_end(&entry);
+    builder.build_return(None);
+
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+    {
+        assert!(!fn_value.has_personality_function());
+        assert!(fn_value.get_personality_function().is_none());
+    }
+
+    fn_value.set_personality_function(&personality);
+
+    #[cfg(not(any(feature = "llvm3-6", feature = "llvm3-7", feature = "llvm3-8")))]
+    assert!(fn_value.has_personality_function());
+    assert_eq!(fn_value.get_personality_function().unwrap(), personality);
+    assert!(module.print_to_string().to_string().contains("personality i32 (...)* @__gxx_personality_v0"));
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_gc() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let builder = context.create_builder();
+    let fn_type = context.void_type().fn_type(&[], false);
+    let fn_value = module.add_function("my_fn", &fn_type, None);
+    let entry = fn_value.append_basic_block("entry");
+
+    builder.position_at_end(&entry);
+    builder.build_return(None);
+
+    assert_eq!(fn_value.get_gc().to_bytes(), b"");
+
+    fn_value.set_gc("shadow-stack");
+
+    assert_eq!(fn_value.get_gc().to_str(), Ok("shadow-stack"));
+    assert!(module.print_to_string().to_string().contains("define void @my_fn() gc \"shadow-stack\""));
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_function_is_declaration() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let builder = context.create_builder();
+    let fn_type = context.void_type().fn_type(&[], false);
+    let decl_fn = module.add_function("my_decl", &fn_type, None);
+    let def_fn = module.add_function("my_def", &fn_type, None);
+    let entry = def_fn.append_basic_block("entry");
+
+    builder.position_at_end(&entry);
+    builder.build_return(None);
+
+    assert!(decl_fn.is_declaration());
+    assert!(!def_fn.is_declaration());
+
+    let ir = module.print_to_string().to_string();
+
+    assert!(ir.contains("declare void @my_decl()"));
+    assert!(ir.contains("define void @my_def()"));
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+fn test_unnamed_address() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let i8_type = context.i8_type();
+    let i8_array_type = i8_type.array_type(2);
+    let i8_array = i8_array_type.const_array(&[i8_type.const_int(104, false), i8_type.const_int(105, false)]);
+    let global = module.add_global(i8_array_type, None, "my_str");
+
+    global.set_linkage(Private);
+    global.set_initializer(&i8_array);
+    global.set_constant(true);
+
+    assert_eq!(global.get_unnamed_address(), UnnamedAddress::None);
+
+    global.set_unnamed_address(UnnamedAddress::Global);
+
+    assert_eq!(global.get_unnamed_address(), UnnamedAddress::Global);
+    assert!(global.has_unnamed_addr());
+    assert!(module.print_to_string().to_string_lossy().contains("@my_str = private unnamed_addr constant [2 x i8] c\"hi\""));
+
+    global.set_unnamed_address(UnnamedAddress::None);
+
+    assert_eq!(global.get_unnamed_address(), UnnamedAddress::None);
+}
+
+#[test]
+fn test_function_params() {
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into(), i32_type.into()], false);
+    let fn_value = module.add_function("my_fn", &fn_type, None);
+
+    assert_eq!(fn_value.count_params(), 2);
+
+    let params = fn_value.get_params();
+
+    assert_eq!(params.len(), 2);
+    assert_eq!(fn_value.get_param_iter().count(), 2);
+
+    let a = params[0].into_int_value();
+    let b = params[1].into_int_value();
+
+    assert_eq!(fn_value.get_nth_param(0).unwrap().into_int_value(), a);
+    assert_eq!(fn_value.get_nth_param(1).unwrap().into_int_value(), b);
+    assert_eq!(fn_value.get_param_iter().last().unwrap().into_int_value(), b);
+    assert!(fn_value.get_nth_param(2).is_none());
+    assert!(fn_value.get_nth_param(::std::u32::MAX).is_none());
+
+    a.set_name("a");
+    b.set_name("b");
+
+    let entry = fn_value.append_basic_block("entry");
+
+    builder.position_at_end(&entry);
+
+    let sum = builder.build_int_add(a, b, "sum");
+
+    builder.build_return(Some(&sum));
+
+    assert!(module.verify().is_ok());
+    assert!(module.print_to_string().to_string_lossy().contains("define i32 @my_fn(i32 %a, i32 %b)"));
+}
+
 #[test]
 fn test_phi_values() {
     let context = Context::create();
@@ -960,3 +1522,104 @@ fn test_allocations() {
 
     assert_eq!(*heap_array.get_type().print_to_string(), *CString::new("i32*").unwrap());
 }
+
+#[test]
+fn test_replace_all_uses_with() {
+    use self::inkwell::values::BasicValue;
+
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let fn_value = module.add_function("my_fn", &fn_type, None);
+    let entry = fn_value.append_basic_block("entry");
+    let param = fn_value.get_first_param().unwrap().into_int_value();
+
+    builder.position_at_end(&entry);
+
+    let sum = builder.build_int_add(param, param, "sum");
+    let product = builder.build_int_mul(sum, sum, "product");
+
+    builder.build_return(Some(&product));
+
+    let forty_two = i32_type.const_int(42, false);
+
+    sum.as_basic_value_enum().replace_all_uses_with(&forty_two);
+
+    assert_eq!(*product.print_to_string(), *CString::new("  %product = mul i32 42, 42").unwrap());
+
+    // The add no longer has any users, so it can be removed without breaking the function
+    sum.as_instruction().unwrap().remove_from_basic_block();
+
+    assert_eq!(entry.get_first_instruction().unwrap(), product.as_instruction().unwrap());
+    assert!(module.verify().is_ok());
+}
+
+#[test]
+#[should_panic]
+fn test_replace_all_uses_with_mismatched_type() {
+    use self::inkwell::values::BasicValue;
+
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let i64_type = context.i64_type();
+    let fn_type = i32_type.fn_type(&[i32_type.into()], false);
+    let fn_value = module.add_function("my_fn", &fn_type, None);
+    let entry = fn_value.append_basic_block("entry");
+    let param = fn_value.get_first_param().unwrap();
+
+    builder.position_at_end(&entry);
+    builder.build_return(Some(&param));
+
+    param.replace_all_uses_with(&i64_type.const_int(42, false));
+}
+
+#[test]
+fn test_basic_value_use() {
+    use self::inkwell::values::BasicValue;
+
+    let context = Context::create();
+    let module = context.create_module("my_mod");
+    let builder = context.create_builder();
+    let i32_type = context.i32_type();
+    let i32_ptr_type = i32_type.ptr_type(AddressSpace::Generic);
+    let fn_type = context.void_type().fn_type(&[i32_type.into(), i32_ptr_type.into()], false);
+    let fn_value = module.add_function("my_fn", &fn_type, None);
+    let entry = fn_value.append_basic_block("entry");
+    let param = fn_value.get_first_param().unwrap().into_int_value();
+    let ptr_param = fn_value.get_nth_param(1).unwrap().into_pointer_value();
+
+    assert!(param.get_first_use().is_none());
+
+    builder.position_at_end(&entry);
+
+    let sum = builder.build_int_add(param, param, "sum");
+    let store = builder.build_store(ptr_param, param);
+
+    builder.build_return(None);
+
+    assert!(sum.get_first_use().is_none());
+
+    let mut opcodes = Vec::new();
+    let mut use_ = param.get_first_use();
+
+    while let Some(param_use) = use_ {
+        assert_eq!(param_use.get_used_value().into_int_value(), param);
+
+        opcodes.push(param_use.get_user().into_instruction_value().get_opcode());
+        use_ = param_use.get_next_use();
+    }
+
+    // Both operands of the add, as well as the stored value, use the param
+    assert_eq!(opcodes.len(), 3);
+    assert_eq!(opcodes.iter().filter(|&opcode| *opcode == Add).count(), 2);
+    assert_eq!(opcodes.iter().filter(|&opcode| *opcode == Store).count(), 1);
+
+    let ptr_use = ptr_param.get_first_use().unwrap();
+
+    assert_eq!(ptr_use.get_user().into_instruction_value(), store);
+    assert!(ptr_use.get_next_use().is_none());
+}